@@ -0,0 +1,158 @@
+//! # Access
+//!
+//! Defines [`TownAccess`], which works out what a [`Player`] may do in a
+//! [`Town`] from the town's [`TownyPermissions`].
+//!
+//! ```
+//! use earthmc_types::{
+//!     access::{DecidedBy, TownAccess},
+//!     nation::Nation,
+//!     player::Player,
+//!     town::Town,
+//! };
+//!
+//! fn describe(player: &Player, town: &Town, nation: Option<&Nation>) {
+//!     let access = TownAccess::new(player, town, nation);
+//!     for decision in access.check_all() {
+//!         let reason = match decision.decided_by {
+//!             DecidedBy::Trusted => "trusted".to_string(),
+//!             DecidedBy::Permission(level) => format!("{level:?} flag"),
+//!         };
+//!         println!(
+//!             "{:?}: {} ({reason})",
+//!             decision.action, decision.allowed
+//!         );
+//!     }
+//! }
+//! ```
+//!
+//! [`TownyPermissions`]: crate::permission::TownyPermissions
+use crate::{
+    nation::Nation,
+    permission::{TownyAction, TownyPermissionLevel},
+    player::Player,
+    town::Town,
+};
+
+/// How a [`Player`] relates to a [`Town`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Relationship {
+    /// Which permission flags apply to the player.
+    pub level: TownyPermissionLevel,
+    /// Whether the town trusts the player, which grants every action.
+    pub is_trusted: bool,
+    /// Whether the town has outlawed the player. Like Towny, this doesn't
+    /// change which flags apply; it only bars the player from the town's
+    /// spawn and may get them teleported out.
+    pub is_outlaw: bool,
+}
+
+impl Relationship {
+    /// Works out `player`'s relationship to `town`.
+    ///
+    /// `nation` should be the town's nation, which is needed to tell allies
+    /// apart from outsiders. A nation other than the town's is ignored.
+    pub fn between(
+        player: &Player,
+        town: &Town,
+        nation: Option<&Nation>,
+    ) -> Self {
+        let nation = nation.filter(|n| town.nation.uuid == Some(n.uuid));
+
+        let level = if player.town.uuid == Some(town.uuid) {
+            TownyPermissionLevel::Resident
+        } else if let Some(player_nation) = player.nation.uuid {
+            if town.nation.uuid == Some(player_nation) {
+                TownyPermissionLevel::Nation
+            } else if nation.is_some_and(|n| {
+                n.allies.iter().any(|ally| ally.uuid == player_nation)
+            }) {
+                TownyPermissionLevel::Ally
+            } else {
+                TownyPermissionLevel::Outsider
+            }
+        } else {
+            TownyPermissionLevel::Outsider
+        };
+
+        Self {
+            level,
+            is_trusted: town.trusted.iter().any(|p| p.uuid == player.uuid),
+            is_outlaw: town.outlaws.iter().any(|p| p.uuid == player.uuid),
+        }
+    }
+}
+
+/// What decided whether an action is allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecidedBy {
+    /// The town trusts the player.
+    Trusted,
+    /// The action's flag for this permission level.
+    Permission(TownyPermissionLevel),
+}
+
+/// Whether an action is allowed, and why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decision {
+    pub action: TownyAction,
+    pub allowed: bool,
+    pub decided_by: DecidedBy,
+}
+
+/// Answers what a [`Player`] may do in a [`Town`].
+///
+/// This only looks at the town-wide permissions; plots with their own
+/// permissions may differ.
+#[derive(Debug, Clone, Copy)]
+pub struct TownAccess<'a> {
+    town: &'a Town,
+    relationship: Relationship,
+}
+
+impl<'a> TownAccess<'a> {
+    /// Evaluates `player`'s access to `town`. See [`Relationship::between`]
+    /// for what `nation` should be.
+    pub fn new(
+        player: &Player,
+        town: &'a Town,
+        nation: Option<&Nation>,
+    ) -> Self {
+        Self {
+            town,
+            relationship: Relationship::between(player, town, nation),
+        }
+    }
+
+    pub fn relationship(&self) -> Relationship {
+        self.relationship
+    }
+
+    /// Whether the player may perform `action`.
+    pub fn can(&self, action: TownyAction) -> bool {
+        self.check(action).allowed
+    }
+
+    /// Whether the player may perform `action`, and what decided it.
+    pub fn check(&self, action: TownyAction) -> Decision {
+        if self.relationship.is_trusted {
+            return Decision {
+                action,
+                allowed: true,
+                decided_by: DecidedBy::Trusted,
+            };
+        }
+
+        let level = self.relationship.level;
+        Decision {
+            action,
+            allowed: self.town.perms.get(action).get(level),
+            decided_by: DecidedBy::Permission(level),
+        }
+    }
+
+    /// The decision for every [`TownyAction`].
+    pub fn check_all(&self) -> [Decision; 4] {
+        TownyAction::ALL.map(|action| self.check(action))
+    }
+}
@@ -0,0 +1,336 @@
+//! # Colour
+//!
+//! Defines the [`Rgb`] and [`Rgba`] colour types.
+//!
+//! [`Rgb`] is used for nation dynmap colours, which the API sends as hex
+//! strings such as `"0000FF"`, and [`Rgba`] for Quarter colours, which it
+//! sends as arrays of four bytes. Both keep their wire format when
+//! serialized.
+use std::{error::Error, fmt, str::FromStr};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer, de, ser::SerializeTuple,
+};
+
+/// An error returned when parsing a hex colour fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColourError {
+    InvalidLength {
+        expected: &'static str,
+        found: usize,
+    },
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseColourError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, found } => {
+                write!(f, "expected {expected} hex digits, found {found}")
+            }
+            Self::InvalidDigit(c) => write!(f, "invalid hex digit {c:?}"),
+        }
+    }
+}
+
+impl Error for ParseColourError {}
+
+/// An opaque colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(type = "string"))]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// A colour with an alpha channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "ts-rs",
+    derive(ts_rs::TS),
+    ts(type = "[number, number, number, number]")
+)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgb {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Unpacks a colour from `0xRRGGBB`, ignoring the highest byte.
+    pub const fn from_u32(packed: u32) -> Self {
+        Self::new((packed >> 16) as u8, (packed >> 8) as u8, packed as u8)
+    }
+
+    /// Packs the colour as `0xRRGGBB`.
+    pub const fn to_u32(&self) -> u32 {
+        (self.r as u32) << 16 | (self.g as u32) << 8 | self.b as u32
+    }
+
+    /// Adds an alpha channel.
+    pub const fn with_alpha(&self, a: u8) -> Rgba {
+        Rgba::new(self.r, self.g, self.b, a)
+    }
+
+    /// The colour as upper case hex digits without a leading `#`, the way the
+    /// API sends it, e.g. `"0000FF"`.
+    pub fn to_hex(&self) -> String {
+        format!("{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
+    /// The colour as a CSS hex colour, e.g. `"#0000FF"`.
+    pub fn to_css(&self) -> String {
+        format!("#{}", self.to_hex())
+    }
+
+    /// The ANSI escape sequence that sets the terminal's foreground to this
+    /// colour.
+    pub fn ansi_fg(&self) -> String {
+        format!("\x1b[38;2;{};{};{}m", self.r, self.g, self.b)
+    }
+
+    /// The ANSI escape sequence that sets the terminal's background to this
+    /// colour.
+    pub fn ansi_bg(&self) -> String {
+        format!("\x1b[48;2;{};{};{}m", self.r, self.g, self.b)
+    }
+}
+
+impl Rgba {
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Unpacks a colour from `0xRRGGBBAA`.
+    pub const fn from_u32_rgba(packed: u32) -> Self {
+        let [r, g, b, a] = packed.to_be_bytes();
+        Self::new(r, g, b, a)
+    }
+
+    /// Packs the colour as `0xRRGGBBAA`.
+    pub const fn to_u32_rgba(&self) -> u32 {
+        u32::from_be_bytes([self.r, self.g, self.b, self.a])
+    }
+
+    /// Unpacks a colour from `0xAARRGGBB`.
+    pub const fn from_u32_argb(packed: u32) -> Self {
+        let [a, r, g, b] = packed.to_be_bytes();
+        Self::new(r, g, b, a)
+    }
+
+    /// Packs the colour as `0xAARRGGBB`.
+    pub const fn to_u32_argb(&self) -> u32 {
+        u32::from_be_bytes([self.a, self.r, self.g, self.b])
+    }
+
+    /// The colour without its alpha channel.
+    pub const fn rgb(&self) -> Rgb {
+        Rgb::new(self.r, self.g, self.b)
+    }
+
+    /// The colour as upper case hex digits without a leading `#`, e.g.
+    /// `"3FB4FFFF"`.
+    pub fn to_hex(&self) -> String {
+        format!("{}{:02X}", self.rgb().to_hex(), self.a)
+    }
+
+    /// The colour as a CSS `rgba()` function, e.g.
+    /// `"rgba(63, 180, 255, 0.5)"`.
+    pub fn to_css(&self) -> String {
+        let alpha = format!("{:.3}", self.a as f64 / 255.0);
+        let alpha = alpha.trim_end_matches('0').trim_end_matches('.');
+        format!("rgba({}, {}, {}, {})", self.r, self.g, self.b, alpha)
+    }
+
+    /// The ANSI escape sequence that sets the terminal's foreground to this
+    /// colour. Terminals have no transparency, so the alpha channel is
+    /// ignored.
+    pub fn ansi_fg(&self) -> String {
+        self.rgb().ansi_fg()
+    }
+
+    /// The ANSI escape sequence that sets the terminal's background to this
+    /// colour, ignoring the alpha channel.
+    pub fn ansi_bg(&self) -> String {
+        self.rgb().ansi_bg()
+    }
+}
+
+impl From<Rgb> for Rgba {
+    fn from(rgb: Rgb) -> Self {
+        rgb.with_alpha(u8::MAX)
+    }
+}
+
+impl From<[u8; 3]> for Rgb {
+    fn from([r, g, b]: [u8; 3]) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<Rgb> for [u8; 3] {
+    fn from(rgb: Rgb) -> Self {
+        [rgb.r, rgb.g, rgb.b]
+    }
+}
+
+impl From<[u8; 4]> for Rgba {
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+impl From<Rgba> for [u8; 4] {
+    fn from(rgba: Rgba) -> Self {
+        [rgba.r, rgba.g, rgba.b, rgba.a]
+    }
+}
+
+/// Formats as a CSS hex colour, e.g. `#0000FF`.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_css())
+    }
+}
+
+/// Formats as a hex colour with alpha, e.g. `#3FB4FFFF`.
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.to_hex())
+    }
+}
+
+/// Parses `RRGGBB` or the `RGB` shorthand, with or without a leading `#`.
+impl FromStr for Rgb {
+    type Err = ParseColourError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = parse_hex_digits(s)?;
+        match digits.as_slice() {
+            [r, g, b] => Ok(Self::new(r * 17, g * 17, b * 17)),
+            [r1, r2, g1, g2, b1, b2] => {
+                Ok(Self::new(r1 << 4 | r2, g1 << 4 | g2, b1 << 4 | b2))
+            }
+            _ => Err(ParseColourError::InvalidLength {
+                expected: "3 or 6",
+                found: digits.len(),
+            }),
+        }
+    }
+}
+
+/// Parses `RRGGBBAA`, or `RRGGBB` as an opaque colour, with or without a
+/// leading `#`.
+impl FromStr for Rgba {
+    type Err = ParseColourError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = parse_hex_digits(s)?;
+        match digits.as_slice() {
+            [_, _, _, _, _, _] => s.parse::<Rgb>().map(Rgba::from),
+            [r1, r2, g1, g2, b1, b2, a1, a2] => Ok(Self::new(
+                r1 << 4 | r2,
+                g1 << 4 | g2,
+                b1 << 4 | b2,
+                a1 << 4 | a2,
+            )),
+            _ => Err(ParseColourError::InvalidLength {
+                expected: "6 or 8",
+                found: digits.len(),
+            }),
+        }
+    }
+}
+
+fn parse_hex_digits(s: &str) -> Result<Vec<u8>, ParseColourError> {
+    let s = s.strip_prefix('#').unwrap_or(s);
+    s.chars()
+        .map(|c| {
+            c.to_digit(16)
+                .map(|digit| digit as u8)
+                .ok_or(ParseColourError::InvalidDigit(c))
+        })
+        .collect()
+}
+
+/// Serializes as upper case hex digits without a leading `#`, like the API.
+impl Serialize for Rgb {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+impl<'de> Deserialize<'de> for Rgb {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Serializes as an array of four bytes, like the API.
+impl Serialize for Rgba {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_tuple(4)?;
+        seq.serialize_element(&self.r)?;
+        seq.serialize_element(&self.g)?;
+        seq.serialize_element(&self.b)?;
+        seq.serialize_element(&self.a)?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Rgba {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[u8; 4]>::deserialize(deserializer).map(Rgba::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Rgb {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Rgb".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "A hex colour without a leading `#`, e.g. `0000FF`.",
+            "type": "string",
+            "pattern": "^#?([0-9A-Fa-f]{3}|[0-9A-Fa-f]{6})$",
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Rgba {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Rgba".into()
+    }
+
+    fn json_schema(
+        generator: &mut schemars::SchemaGenerator,
+    ) -> schemars::Schema {
+        let mut schema = <[u8; 4]>::json_schema(generator);
+        schema
+            .insert("description".into(), "Red, green, blue and alpha.".into());
+        schema
+    }
+}
@@ -0,0 +1,17 @@
+//! # Discord Link
+//!
+//! Defines the [`DiscordLink`] struct.
+use crate::id::PlayerId;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct DiscordLink {
+    /// Discord User ID.
+    pub id: String,
+    /// Minecraft UUID.
+    pub uuid: PlayerId,
+}
@@ -0,0 +1,390 @@
+//! # Formatting
+//!
+//! Defines [`FormattedText`], which parses Minecraft formatting codes into
+//! styled [`Span`]s.
+//!
+//! Fields such as `Player.formatted_name`, `Player.about` and `Town.board`
+//! can contain legacy codes prefixed with `§` or `&` (e.g. `§aGreen`), as well
+//! as hex colours written as `§x§R§R§G§G§B§B` or `&#RRGGBB`.
+//!
+//! ```
+//! use earthmc_types::formatting::FormattedText;
+//!
+//! let text = FormattedText::parse("§x§A§0§2§0§F§0Doge §lCorruptedGreed");
+//! assert_eq!(text.to_plain(), "Doge CorruptedGreed");
+//! assert_eq!(text.to_discord_markdown(), "Doge **CorruptedGreed**");
+//! ```
+use std::fmt::{self, Write};
+
+use crate::colour::Rgb;
+
+/// One of the 16 named colours available through legacy formatting codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedColour {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+}
+
+impl NamedColour {
+    /// Every named colour, in code order.
+    pub const ALL: [NamedColour; 16] = [
+        Self::Black,
+        Self::DarkBlue,
+        Self::DarkGreen,
+        Self::DarkAqua,
+        Self::DarkRed,
+        Self::DarkPurple,
+        Self::Gold,
+        Self::Gray,
+        Self::DarkGray,
+        Self::Blue,
+        Self::Green,
+        Self::Aqua,
+        Self::Red,
+        Self::LightPurple,
+        Self::Yellow,
+        Self::White,
+    ];
+
+    /// Looks up a colour by its formatting code, e.g. `'a'` for
+    /// [`NamedColour::Green`]. Codes are case insensitive.
+    pub fn from_code(code: char) -> Option<Self> {
+        code.to_digit(16).map(|index| Self::ALL[index as usize])
+    }
+
+    /// The colour's formatting code, from `'0'` to `'f'`.
+    pub fn code(&self) -> char {
+        char::from_digit(*self as u32, 16).unwrap()
+    }
+
+    /// The colour as rendered by the vanilla client.
+    pub fn rgb(&self) -> Rgb {
+        Rgb::from_u32(match self {
+            Self::Black => 0x000000,
+            Self::DarkBlue => 0x0000AA,
+            Self::DarkGreen => 0x00AA00,
+            Self::DarkAqua => 0x00AAAA,
+            Self::DarkRed => 0xAA0000,
+            Self::DarkPurple => 0xAA00AA,
+            Self::Gold => 0xFFAA00,
+            Self::Gray => 0xAAAAAA,
+            Self::DarkGray => 0x555555,
+            Self::Blue => 0x5555FF,
+            Self::Green => 0x55FF55,
+            Self::Aqua => 0x55FFFF,
+            Self::Red => 0xFF5555,
+            Self::LightPurple => 0xFF55FF,
+            Self::Yellow => 0xFFFF55,
+            Self::White => 0xFFFFFF,
+        })
+    }
+}
+
+/// The colour of a [`Span`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextColour {
+    Named(NamedColour),
+    Hex(Rgb),
+}
+
+impl TextColour {
+    pub fn rgb(&self) -> Rgb {
+        match self {
+            Self::Named(named) => named.rgb(),
+            Self::Hex(rgb) => *rgb,
+        }
+    }
+}
+
+/// The style applied to a [`Span`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub colour: Option<TextColour>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underlined: bool,
+    pub strikethrough: bool,
+    pub obfuscated: bool,
+}
+
+impl Style {
+    /// Whether this is the default, unstyled style.
+    pub fn is_plain(&self) -> bool {
+        *self == Style::default()
+    }
+}
+
+/// A run of text sharing one [`Style`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+
+/// Text parsed from a string containing Minecraft formatting codes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct FormattedText {
+    pub spans: Vec<Span>,
+}
+
+impl FormattedText {
+    /// Parses a string containing `§` or `&` formatting codes.
+    ///
+    /// Colour codes reset any active formatting, as they do in-game. A prefix
+    /// that isn't followed by a valid code is kept as literal text, so
+    /// strings like `"Tom & Jerry"` are left intact.
+    pub fn parse(raw: &str) -> Self {
+        let mut text = FormattedText::default();
+        let mut style = Style::default();
+        let mut buffer = String::new();
+        let mut rest = raw;
+
+        while let Some(c) = rest.chars().next() {
+            if let Some((change, remaining)) = parse_code(rest) {
+                text.push(std::mem::take(&mut buffer), style);
+                match change {
+                    StyleChange::Colour(colour) => {
+                        style = Style {
+                            colour: Some(colour),
+                            ..Style::default()
+                        }
+                    }
+                    StyleChange::Bold => style.bold = true,
+                    StyleChange::Italic => style.italic = true,
+                    StyleChange::Underlined => style.underlined = true,
+                    StyleChange::Strikethrough => style.strikethrough = true,
+                    StyleChange::Obfuscated => style.obfuscated = true,
+                    StyleChange::Reset => style = Style::default(),
+                }
+                rest = remaining;
+            } else {
+                buffer.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        text.push(buffer, style);
+        text
+    }
+
+    fn push(&mut self, text: String, style: Style) {
+        if text.is_empty() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(&text),
+            _ => self.spans.push(Span { text, style }),
+        }
+    }
+
+    /// The text with all formatting removed.
+    pub fn to_plain(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// The text with ANSI escape sequences for terminals that support 24-bit
+    /// colour. Obfuscated text is shown as-is.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for span in &self.spans {
+            if span.style.is_plain() {
+                out.push_str(&span.text);
+                continue;
+            }
+            let style = &span.style;
+            if let Some(colour) = style.colour {
+                out.push_str(&colour.rgb().ansi_fg());
+            }
+            for (enabled, code) in [
+                (style.bold, 1),
+                (style.italic, 3),
+                (style.underlined, 4),
+                (style.strikethrough, 9),
+            ] {
+                if enabled {
+                    write!(out, "\x1b[{code}m").unwrap();
+                }
+            }
+            out.push_str(&span.text);
+            out.push_str("\x1b[0m");
+        }
+        out
+    }
+
+    /// The text as HTML, with styled spans wrapped in `<span>` elements
+    /// carrying inline styles. Obfuscated text is shown as-is.
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        for span in &self.spans {
+            let style = &span.style;
+            let mut css = Vec::new();
+            if let Some(colour) = style.colour {
+                css.push(format!("color: {}", colour.rgb().to_css()));
+            }
+            if style.bold {
+                css.push("font-weight: bold".to_owned());
+            }
+            if style.italic {
+                css.push("font-style: italic".to_owned());
+            }
+            let decorations: Vec<_> = [
+                (style.underlined, "underline"),
+                (style.strikethrough, "line-through"),
+            ]
+            .into_iter()
+            .filter_map(|(enabled, name)| enabled.then_some(name))
+            .collect();
+            if !decorations.is_empty() {
+                css.push(format!("text-decoration: {}", decorations.join(" ")));
+            }
+
+            if css.is_empty() {
+                escape_html(&span.text, &mut out);
+            } else {
+                write!(out, "<span style=\"{}\">", css.join("; ")).unwrap();
+                escape_html(&span.text, &mut out);
+                out.push_str("</span>");
+            }
+        }
+        out
+    }
+
+    /// The text as Discord markdown. Colours are dropped, and obfuscated text
+    /// becomes a spoiler.
+    pub fn to_discord_markdown(&self) -> String {
+        let mut out = String::new();
+        for span in &self.spans {
+            let style = &span.style;
+            let markers: String = [
+                (style.obfuscated, "||"),
+                (style.strikethrough, "~~"),
+                (style.underlined, "__"),
+                (style.bold, "**"),
+                (style.italic, "*"),
+            ]
+            .into_iter()
+            .filter_map(|(enabled, marker)| enabled.then_some(marker))
+            .collect();
+
+            // Markdown doesn't apply to surrounding whitespace, so keep it
+            // outside of the markers.
+            let trimmed = span.text.trim();
+            if markers.is_empty() || trimmed.is_empty() {
+                escape_markdown(&span.text, &mut out);
+                continue;
+            }
+            let start = span.text.len() - span.text.trim_start().len();
+            let end = start + trimmed.len();
+            out.push_str(&span.text[..start]);
+            out.push_str(&markers);
+            escape_markdown(trimmed, &mut out);
+            out.extend(markers.chars().rev());
+            out.push_str(&span.text[end..]);
+        }
+        out
+    }
+}
+
+impl From<&str> for FormattedText {
+    fn from(raw: &str) -> Self {
+        Self::parse(raw)
+    }
+}
+
+/// Formats as plain text.
+impl fmt::Display for FormattedText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for span in &self.spans {
+            f.write_str(&span.text)?;
+        }
+        Ok(())
+    }
+}
+
+enum StyleChange {
+    Colour(TextColour),
+    Bold,
+    Italic,
+    Underlined,
+    Strikethrough,
+    Obfuscated,
+    Reset,
+}
+
+/// Parses a formatting code at the start of `s`, returning the change it
+/// makes and the rest of the string.
+fn parse_code(s: &str) -> Option<(StyleChange, &str)> {
+    let prefix = s.chars().next().filter(|c| matches!(c, '§' | '&'))?;
+    let rest = &s[prefix.len_utf8()..];
+    let code = rest.chars().next()?;
+    let after = &rest[code.len_utf8()..];
+
+    let change = match code.to_ascii_lowercase() {
+        'x' => return parse_repeated_hex(prefix, after),
+        '#' => {
+            let digits = after.get(..6)?;
+            let colour = digits.parse().ok()?;
+            return Some((
+                StyleChange::Colour(TextColour::Hex(colour)),
+                &after[6..],
+            ));
+        }
+        'k' => StyleChange::Obfuscated,
+        'l' => StyleChange::Bold,
+        'm' => StyleChange::Strikethrough,
+        'n' => StyleChange::Underlined,
+        'o' => StyleChange::Italic,
+        'r' => StyleChange::Reset,
+        c => StyleChange::Colour(TextColour::Named(NamedColour::from_code(c)?)),
+    };
+    Some((change, after))
+}
+
+/// Parses the `§R§R§G§G§B§B` that follows `§x`.
+fn parse_repeated_hex(prefix: char, s: &str) -> Option<(StyleChange, &str)> {
+    let mut digits = String::with_capacity(6);
+    let mut rest = s;
+    for _ in 0..6 {
+        rest = rest.strip_prefix(prefix)?;
+        let digit = rest.chars().next().filter(char::is_ascii_hexdigit)?;
+        digits.push(digit);
+        rest = &rest[1..];
+    }
+    let colour = digits.parse().ok()?;
+    Some((StyleChange::Colour(TextColour::Hex(colour)), rest))
+}
+
+fn escape_html(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+fn escape_markdown(text: &str, out: &mut String) {
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '~' | '`' | '|') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
@@ -0,0 +1,423 @@
+//! # Geometry
+//!
+//! Defines [`ClaimShape`], which turns a town's claimed chunks into polygons
+//! in block coordinates, and the [`BlockRect`] bounding box.
+//!
+//! Polygon vertices are block corners rather than blocks, so the chunk
+//! `(0, 0)` is the square from `(0, 0)` to `(16, 16)`. Chunks only count as
+//! connected when they share an edge, which is how Towny treats claims.
+//!
+//! ```
+//! use earthmc_types::{geometry::ClaimShape, position::ChunkPos};
+//!
+//! // two chunks side by side and an exclave
+//! let shape = ClaimShape::from_chunks([
+//!     ChunkPos::new(0, 0),
+//!     ChunkPos::new(1, 0),
+//!     ChunkPos::new(5, 5),
+//! ]);
+//! assert_eq!(shape.components().len(), 2);
+//! assert_eq!(shape.area(), 3 * 16 * 16);
+//! assert_eq!(shape.perimeter(), (6 + 4) * 16);
+//! ```
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+};
+
+use crate::{
+    location::PositionXZ,
+    position::{BlockPosXZ, CHUNK_SIZE, ChunkPos},
+    town::Town,
+};
+
+/// An axis-aligned rectangle of blocks, with both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockRect {
+    pub min: BlockPosXZ,
+    pub max: BlockPosXZ,
+}
+
+impl BlockRect {
+    /// Creates the rectangle spanning two opposite corners, in any order.
+    pub fn new(a: BlockPosXZ, b: BlockPosXZ) -> Self {
+        Self {
+            min: BlockPosXZ::new(a.x.min(b.x), a.z.min(b.z)),
+            max: BlockPosXZ::new(a.x.max(b.x), a.z.max(b.z)),
+        }
+    }
+
+    /// The blocks of a chunk.
+    pub const fn of_chunk(chunk: ChunkPos) -> Self {
+        Self {
+            min: chunk.min_block(),
+            max: chunk.max_block(),
+        }
+    }
+
+    /// The width along the x axis, in blocks.
+    pub const fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    /// The depth along the z axis, in blocks.
+    pub const fn depth(&self) -> i32 {
+        self.max.z - self.min.z + 1
+    }
+
+    /// The number of blocks covered.
+    pub const fn area(&self) -> i64 {
+        self.width() as i64 * self.depth() as i64
+    }
+
+    pub const fn contains(&self, block: BlockPosXZ) -> bool {
+        self.min.x <= block.x
+            && block.x <= self.max.x
+            && self.min.z <= block.z
+            && block.z <= self.max.z
+    }
+
+    /// Whether the rectangles share at least one block.
+    pub const fn intersects(&self, other: &BlockRect) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+
+    /// The smallest rectangle containing both.
+    pub fn union(&self, other: &BlockRect) -> BlockRect {
+        Self::new(
+            BlockPosXZ::new(
+                self.min.x.min(other.min.x),
+                self.min.z.min(other.min.z),
+            ),
+            BlockPosXZ::new(
+                self.max.x.max(other.max.x),
+                self.max.z.max(other.max.z),
+            ),
+        )
+    }
+
+    /// The chunks the rectangle overlaps, row by row.
+    pub fn chunks(&self) -> impl Iterator<Item = ChunkPos> + use<> {
+        let (min, max) = (self.min.chunk(), self.max.chunk());
+        (min.z..=max.z).flat_map(move |z| {
+            (min.x..=max.x).map(move |x| ChunkPos::new(x, z))
+        })
+    }
+}
+
+/// A polygon with holes. Vertices are block corners, and only the corners
+/// where the outline turns are kept.
+///
+/// The exterior winds counter-clockwise when x points right and z points up,
+/// and holes wind the other way.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Polygon {
+    pub exterior: Vec<BlockPosXZ>,
+    pub holes: Vec<Vec<BlockPosXZ>>,
+}
+
+/// One connected group of claimed chunks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClaimComponent {
+    chunks: Vec<ChunkPos>,
+    polygon: Polygon,
+    perimeter: i64,
+}
+
+impl ClaimComponent {
+    fn new(chunks: Vec<ChunkPos>) -> Self {
+        let claimed: BTreeSet<ChunkPos> = chunks.iter().copied().collect();
+        let (rings, edges) = trace_rings(&claimed);
+
+        let mut polygon = Polygon::default();
+        for ring in rings {
+            if signed_area(&ring) > 0 {
+                polygon.exterior = ring;
+            } else {
+                polygon.holes.push(ring);
+            }
+        }
+
+        Self {
+            chunks,
+            polygon,
+            perimeter: edges as i64 * CHUNK_SIZE as i64,
+        }
+    }
+
+    /// The claimed chunks, sorted.
+    pub fn chunks(&self) -> &[ChunkPos] {
+        &self.chunks
+    }
+
+    pub fn polygon(&self) -> &Polygon {
+        &self.polygon
+    }
+
+    /// The area in blocks.
+    pub fn area(&self) -> i64 {
+        chunk_area(self.chunks.len())
+    }
+
+    /// The length of the outline in blocks, including the holes.
+    pub fn perimeter(&self) -> i64 {
+        self.perimeter
+    }
+
+    /// The centre of mass. It may fall outside the claim, e.g. for a
+    /// ring-shaped town.
+    pub fn centroid(&self) -> PositionXZ {
+        centroid(&self.chunks)
+    }
+
+    pub fn bounds(&self) -> BlockRect {
+        bounds(&self.chunks)
+    }
+
+    pub fn contains(&self, chunk: ChunkPos) -> bool {
+        self.chunks.binary_search(&chunk).is_ok()
+    }
+}
+
+/// The shape of a set of claimed chunks, split into connected components.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ClaimShape {
+    components: Vec<ClaimComponent>,
+}
+
+impl ClaimShape {
+    /// Builds the shape of a set of chunks. Duplicates are ignored.
+    ///
+    /// Components are sorted largest first.
+    pub fn from_chunks(chunks: impl IntoIterator<Item = ChunkPos>) -> Self {
+        let mut unvisited: BTreeSet<ChunkPos> = chunks.into_iter().collect();
+        let mut components = Vec::new();
+
+        while let Some(start) = unvisited.pop_first() {
+            let mut chunks = vec![start];
+            let mut queue = VecDeque::from([start]);
+            while let Some(chunk) = queue.pop_front() {
+                for neighbour in neighbours(chunk) {
+                    if unvisited.remove(&neighbour) {
+                        chunks.push(neighbour);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            chunks.sort_unstable();
+            components.push(ClaimComponent::new(chunks));
+        }
+
+        // stable, so equal sizes stay ordered by their smallest chunk
+        components.sort_by_key(|c| Reverse(c.chunks.len()));
+        Self { components }
+    }
+
+    /// Builds the shape of a town's claims. The component with the town's
+    /// home block comes first, followed by its exclaves, largest first.
+    pub fn of_town(town: &Town) -> Self {
+        let mut shape =
+            Self::from_chunks(town.coordinates.town_blocks.iter().copied());
+        let home = town.coordinates.home_block;
+        if let Some(i) = shape.components.iter().position(|c| c.contains(home))
+        {
+            let main = shape.components.remove(i);
+            shape.components.insert(0, main);
+        }
+        shape
+    }
+
+    /// Every component, see [`ClaimShape::from_chunks`] and
+    /// [`ClaimShape::of_town`] for the order.
+    pub fn components(&self) -> &[ClaimComponent] {
+        &self.components
+    }
+
+    /// The first component, which for a town is the one with its home block.
+    pub fn main(&self) -> Option<&ClaimComponent> {
+        self.components.first()
+    }
+
+    /// Every component but the main one.
+    pub fn exclaves(&self) -> &[ClaimComponent] {
+        self.components.get(1..).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// The number of claimed chunks.
+    pub fn num_chunks(&self) -> usize {
+        self.components.iter().map(|c| c.chunks.len()).sum()
+    }
+
+    /// Every claimed chunk, component by component.
+    pub fn chunks(&self) -> impl Iterator<Item = ChunkPos> + '_ {
+        self.components
+            .iter()
+            .flat_map(|c| c.chunks.iter().copied())
+    }
+
+    /// The total area in blocks.
+    pub fn area(&self) -> i64 {
+        chunk_area(self.num_chunks())
+    }
+
+    /// The total length of every outline in blocks.
+    pub fn perimeter(&self) -> i64 {
+        self.components.iter().map(|c| c.perimeter).sum()
+    }
+
+    /// The centre of mass of every component together. Returns `None` if
+    /// nothing is claimed.
+    pub fn centroid(&self) -> Option<PositionXZ> {
+        let chunks: Vec<ChunkPos> = self.chunks().collect();
+        (!chunks.is_empty()).then(|| centroid(&chunks))
+    }
+
+    /// The bounding box of every component. Returns `None` if nothing is
+    /// claimed.
+    pub fn bounds(&self) -> Option<BlockRect> {
+        self.components
+            .iter()
+            .map(ClaimComponent::bounds)
+            .reduce(|a, b| a.union(&b))
+    }
+}
+
+impl From<&Town> for ClaimShape {
+    fn from(town: &Town) -> Self {
+        Self::of_town(town)
+    }
+}
+
+fn neighbours(chunk: ChunkPos) -> [ChunkPos; 4] {
+    let ChunkPos { x, z } = chunk;
+    [
+        ChunkPos::new(x + 1, z),
+        ChunkPos::new(x - 1, z),
+        ChunkPos::new(x, z + 1),
+        ChunkPos::new(x, z - 1),
+    ]
+}
+
+fn chunk_area(chunks: usize) -> i64 {
+    chunks as i64 * (CHUNK_SIZE as i64 * CHUNK_SIZE as i64)
+}
+
+fn centroid(chunks: &[ChunkPos]) -> PositionXZ {
+    let n = chunks.len() as f64;
+    let (sum_x, sum_z) = chunks.iter().fold((0.0, 0.0), |(x, z), chunk| {
+        (x + chunk.x as f64, z + chunk.z as f64)
+    });
+    let half = CHUNK_SIZE as f64 / 2.0;
+    PositionXZ {
+        x: (sum_x / n * CHUNK_SIZE as f64 + half) as f32,
+        z: (sum_z / n * CHUNK_SIZE as f64 + half) as f32,
+    }
+}
+
+/// The bounding box of a non-empty list of chunks.
+fn bounds(chunks: &[ChunkPos]) -> BlockRect {
+    let min_x = chunks.iter().map(|c| c.x).min().unwrap_or_default();
+    let max_x = chunks.iter().map(|c| c.x).max().unwrap_or_default();
+    let min_z = chunks.iter().map(|c| c.z).min().unwrap_or_default();
+    let max_z = chunks.iter().map(|c| c.z).max().unwrap_or_default();
+    BlockRect {
+        min: ChunkPos::new(min_x, min_z).min_block(),
+        max: ChunkPos::new(max_x, max_z).max_block(),
+    }
+}
+
+/// Twice the signed area of a ring, positive when it winds
+/// counter-clockwise.
+fn signed_area(ring: &[BlockPosXZ]) -> i64 {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| a.x as i64 * b.z as i64 - b.x as i64 * a.z as i64)
+        .sum()
+}
+
+/// Traces the outlines of a connected set of chunks, returning the rings in
+/// block corners and the number of chunk edges they cover.
+///
+/// Every chunk side without a claimed neighbour becomes an edge, directed so
+/// the claim is on its left. Edges are then followed end to end; where two
+/// outlines touch at a corner, the trace turns away from the claim so that
+/// each ring stays simple and the rings only share that corner.
+fn trace_rings(claimed: &BTreeSet<ChunkPos>) -> (Vec<Vec<BlockPosXZ>>, usize) {
+    // edges as (start, end) chunk corners
+    let mut edges = Vec::new();
+    for &ChunkPos { x, z } in claimed {
+        let sides = [
+            ((x, z - 1), (x, z), (x + 1, z)),
+            ((x + 1, z), (x + 1, z), (x + 1, z + 1)),
+            ((x, z + 1), (x + 1, z + 1), (x, z + 1)),
+            ((x - 1, z), (x, z + 1), (x, z)),
+        ];
+        for ((nx, nz), start, end) in sides {
+            if !claimed.contains(&ChunkPos::new(nx, nz)) {
+                edges.push((start, end));
+            }
+        }
+    }
+
+    let mut by_start: BTreeMap<(i32, i32), Vec<usize>> = BTreeMap::new();
+    for (i, &(start, _)) in edges.iter().enumerate() {
+        by_start.entry(start).or_default().push(i);
+    }
+    let next = |i: usize| {
+        let (start, end) = edges[i];
+        let right = (end.1 - start.1, start.0 - end.0);
+        let outgoing = &by_start[&end];
+        outgoing
+            .iter()
+            .copied()
+            .find(|&j| {
+                let (a, b) = edges[j];
+                (b.0 - a.0, b.1 - a.1) == right
+            })
+            .unwrap_or(outgoing[0])
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+        let mut corners = Vec::new();
+        let mut edge = first;
+        loop {
+            used[edge] = true;
+            corners.push(edges[edge].0);
+            edge = next(edge);
+            if edge == first {
+                break;
+            }
+        }
+        rings.push(simplify(&corners));
+    }
+    (rings, edges.len())
+}
+
+/// Drops the corners where a ring goes straight on and scales the rest to
+/// block coordinates.
+fn simplify(corners: &[(i32, i32)]) -> Vec<BlockPosXZ> {
+    let n = corners.len();
+    (0..n)
+        .filter(|&i| {
+            let (a, b, c) =
+                (corners[(i + n - 1) % n], corners[i], corners[(i + 1) % n]);
+            (b.0 - a.0, b.1 - a.1) != (c.0 - b.0, c.1 - b.1)
+        })
+        .map(|i| {
+            let (x, z) = corners[i];
+            BlockPosXZ::new(x * CHUNK_SIZE, z * CHUNK_SIZE)
+        })
+        .collect()
+}
@@ -0,0 +1,182 @@
+//! # ID
+//!
+//! Defines the [`Id`] struct, a [`Uuid`] tagged with the kind of entity it
+//! identifies.
+//!
+//! Each entity has its own alias ([`TownId`], [`NationId`], [`PlayerId`] and
+//! [`QuarterId`]), so passing e.g. a nation's ID where a player's is expected
+//! fails to compile.
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use uuid::Uuid;
+
+use crate::{nation::Nation, player::Player, quarter::Quarter, town::Town};
+
+/// The [`Uuid`] of an entity of kind `K`.
+pub struct Id<K> {
+    uuid: Uuid,
+    kind: PhantomData<fn() -> K>,
+}
+
+/// The ID of a [`Town`].
+pub type TownId = Id<Town>;
+/// The ID of a [`Nation`].
+pub type NationId = Id<Nation>;
+/// The ID of a [`Player`].
+pub type PlayerId = Id<Player>;
+/// The ID of a [`Quarter`].
+pub type QuarterId = Id<Quarter>;
+
+impl<K> Id<K> {
+    /// Tags `uuid` as the ID of an entity of kind `K`.
+    pub const fn new(uuid: Uuid) -> Self {
+        Self {
+            uuid,
+            kind: PhantomData,
+        }
+    }
+
+    /// The underlying [`Uuid`].
+    pub const fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+}
+
+impl<K> From<Id<K>> for Uuid {
+    fn from(id: Id<K>) -> Self {
+        id.uuid
+    }
+}
+
+impl<K> Clone for Id<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for Id<K> {}
+
+/// The nil [`Uuid`].
+impl<K> Default for Id<K> {
+    fn default() -> Self {
+        Self::new(Uuid::nil())
+    }
+}
+
+impl<K> PartialEq for Id<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid == other.uuid
+    }
+}
+
+impl<K> Eq for Id<K> {}
+
+impl<K> PartialOrd for Id<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K> Ord for Id<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.uuid.cmp(&other.uuid)
+    }
+}
+
+impl<K> Hash for Id<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state);
+    }
+}
+
+/// Formats the same as the underlying [`Uuid`].
+impl<K> fmt::Debug for Id<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.uuid, f)
+    }
+}
+
+impl<K> fmt::Display for Id<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.uuid, f)
+    }
+}
+
+impl<K> FromStr for Id<K> {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::from_str(s).map(Self::new)
+    }
+}
+
+impl<K> Serialize for Id<K> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.uuid.serialize(serializer)
+    }
+}
+
+impl<'de, K> Deserialize<'de> for Id<K> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Uuid::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Serializes as a plain UUID, like [`Uuid`].
+#[cfg(feature = "schemars")]
+impl<K> schemars::JsonSchema for Id<K> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        Uuid::schema_name()
+    }
+
+    fn json_schema(
+        generator: &mut schemars::SchemaGenerator,
+    ) -> schemars::Schema {
+        Uuid::json_schema(generator)
+    }
+}
+
+/// Serializes as a plain UUID string. Declaring it on its own gives
+/// `type Id = string;`.
+#[cfg(feature = "ts-rs")]
+impl<K> ts_rs::TS for Id<K> {
+    type WithoutGenerics = Self;
+    type OptionInnerType = Self;
+
+    fn name() -> String {
+        "string".to_owned()
+    }
+
+    fn inline() -> String {
+        "string".to_owned()
+    }
+
+    fn inline_flattened() -> String {
+        Self::inline()
+    }
+
+    fn decl() -> String {
+        "type Id = string;".to_owned()
+    }
+
+    fn decl_concrete() -> String {
+        Self::decl()
+    }
+}
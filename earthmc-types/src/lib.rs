@@ -0,0 +1,46 @@
+//! `earthmc-types` contains the data types returned by the
+//! [EarthMC](https://earthmc.net) API, such as towns, nations and players.
+//!
+//! It only depends on `serde` and `uuid`, so it can be used to deserialize
+//! EarthMC JSON without pulling in an HTTP client. The
+//! [`earthmc`](https://docs.rs/earthmc) crate re-exports every module here
+//! alongside its async client.
+//!
+//! ## Features
+//!
+//! Every timestamp is a [`Timestamp`](timestamp::Timestamp) of Unix
+//! milliseconds that converts to `SystemTime`. Enable the `chrono` or `time`
+//! feature to also convert it to the date-time types of those crates.
+//!
+//! Enable the `schemars` feature to derive `schemars::JsonSchema` for every
+//! model. The schemas follow the API's wire format, so they can be used to
+//! validate EarthMC JSON outside of Rust.
+//!
+//! Enable the `ts-rs` feature to derive `ts_rs::TS` for every model, which
+//! generates TypeScript definitions with the same field names as the JSON. See
+//! the `export_typescript` example.
+pub mod access;
+pub mod colour;
+pub mod discord_link;
+pub mod formatting;
+pub mod geometry;
+pub mod id;
+pub mod location;
+pub mod mystery_master;
+pub mod named_id;
+pub mod nation;
+pub mod nearby;
+pub mod permission;
+pub mod player;
+pub mod player_stats;
+pub mod position;
+pub mod quarter;
+pub mod quarter_check;
+pub mod server;
+pub mod spatial;
+pub mod stat_history;
+pub mod territory;
+pub mod timestamp;
+pub mod town;
+pub mod world;
+pub mod world_location;
@@ -0,0 +1,26 @@
+//! # Location
+//!
+//! Defines the [`LocationInfo`] struct.
+use serde::{Deserialize, Serialize};
+
+use crate::{named_id::NamedIdOpt, nation::Nation, town::Town};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct LocationInfo {
+    pub location: PositionXZ,
+    pub is_wilderness: bool,
+    pub town: NamedIdOpt<Town>,
+    pub nation: NamedIdOpt<Nation>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct PositionXZ {
+    pub x: f32,
+    pub z: f32,
+}
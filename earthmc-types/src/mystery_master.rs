@@ -0,0 +1,34 @@
+//! # Mystery Master
+//!
+//! Defines the [`MysteryMaster`] struct.
+use crate::id::PlayerId;
+use serde::{Deserialize, Serialize};
+
+/// Represents a player participating in "Mystery Master". Change indiciates in
+/// what direction they have moved on the scoreboard.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct MysteryMaster {
+    /// The player's username.
+    pub name: String,
+    /// The player's ID.
+    pub uuid: PlayerId,
+    /// The change kind.
+    pub change: MysteryMasterChangeKind,
+}
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MysteryMasterChangeKind {
+    Down,
+    #[default]
+    Unchanged,
+    Up,
+}
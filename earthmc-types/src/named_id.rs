@@ -0,0 +1,25 @@
+//! # Named ID
+//!
+//! Defines the [`NamedIdGeneric`] structs for objects with just a name and an
+//! ID.
+use serde::{Deserialize, Serialize};
+
+use crate::id::Id;
+
+/// An object with a name and an ID which can be specialized to
+/// `(String, Id<K>)` or `(Option<String>, Option<Id<K>>)`.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+pub struct NamedIdGeneric<N, U> {
+    pub name: N,
+    pub uuid: U,
+}
+
+/// A reference to an entity of kind `K`, e.g. `NamedId<Town>`.
+pub type NamedId<K> = NamedIdGeneric<String, Id<K>>;
+
+/// An optional reference to an entity of kind `K`, e.g. a town's nation.
+pub type NamedIdOpt<K> = NamedIdGeneric<Option<String>, Option<Id<K>>>;
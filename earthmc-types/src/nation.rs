@@ -0,0 +1,132 @@
+//! # Nation
+//!
+//! Defines the [`Nation`] struct.
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    colour::Rgb, id::NationId, named_id::NamedId, player::Player,
+    timestamp::Timestamp, town::Town, world_location::WorldLocation,
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct Nation {
+    /// The nation's name.
+    pub name: String,
+    /// The nation's ID.
+    pub uuid: NationId,
+    /// The nation's board as seen on `/n` in-game.
+    pub board: Option<String>,
+    /// The nation's dynmap colour.
+    pub dynmap_colour: Rgb,
+    /// The nation's dynmap outline colour.
+    pub dynmap_outline: Rgb,
+    /// The nation's wiki URL.
+    pub wiki: Option<String>,
+    /// The nation's leader (mayor of the capital town).
+    #[serde(rename = "king")]
+    pub leader: NamedId<Player>,
+    /// The nation's capital town.
+    pub capital: NamedId<Town>,
+    pub timestamps: NationTimestamps,
+    pub status: NationStatus,
+    pub stats: NationStats,
+    pub coordinates: NationCoordinates,
+    /// A list of all residents of the nation.
+    pub residents: Vec<NamedId<Player>>,
+    /// A list of all towns of the nation.
+    pub towns: Vec<NamedId<Town>>,
+    /// A list of all allied nations of the nation.
+    pub allies: Vec<NamedId<Nation>>,
+    /// A list of all enemy nations of the nation.
+    pub enemies: Vec<NamedId<Nation>>,
+    /// A list of all sanctioned towns of the nation.
+    pub sanctioned: Vec<NamedId<Town>>,
+    pub ranks: BTreeMap<NationRankKind, Vec<NamedId<Player>>>,
+}
+
+impl Nation {
+    /// The time elapsed since the nation was created.
+    pub fn age(&self) -> Duration {
+        self.timestamps.registered.elapsed()
+    }
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct NationTimestamps {
+    /// When the nation was created.
+    pub registered: Timestamp,
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct NationStatus {
+    /// If the nation is public.
+    pub is_public: bool,
+    /// If the nation is open (invite-less joining enabled).
+    pub is_open: bool,
+    /// If the nation is neutral.
+    pub is_neutral: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct NationStats {
+    /// The total number of town blocks the nation has.
+    pub num_town_blocks: i32,
+    /// The current number of residents in the nation.
+    pub num_residents: i32,
+    /// The current number of towns in the nation.
+    pub num_towns: i32,
+    /// The current number of allied nations.
+    pub num_allies: i32,
+    /// The current number of enemy nations.
+    pub num_enemies: i32,
+    /// The nation's balance as seen on `/n` in-game.
+    pub balance: f64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct NationCoordinates {
+    /// The location of the nation's spawn.
+    pub spawn: WorldLocation,
+}
+
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "PascalCase")]
+pub enum NationRankKind {
+    Chancellor,
+    Colonist,
+    Diplomat,
+}
@@ -0,0 +1,274 @@
+//! # Nearby
+//!
+//! Defines [`Nearby`], which searches a set of towns for the towns, nations
+//! and capitals around a town or coordinate, without calling the API.
+//!
+//! A [`NearbySearch`] has the shape of one item of a `nearby` endpoint query
+//! and runs with [`Nearby::query`]. It is meant to return the towns the
+//! endpoint returns for the same target and radius, sorted by distance. This
+//! hasn't been checked against recorded responses yet; the `compare_nearby`
+//! example in the `earthmc` crate compares the two against the live API.
+//!
+//! ```
+//! use earthmc_types::{
+//!     nearby::{DistanceTo, Nearby, NearbyOrigin},
+//!     position::{BlockPosXZ, ChunkPos},
+//!     town::Town,
+//! };
+//!
+//! let town = |name: &str, x, z| {
+//!     let mut town = Town::default();
+//!     town.name = name.to_string();
+//!     town.coordinates.home_block = ChunkPos::new(x, z);
+//!     town.coordinates.town_blocks = vec![ChunkPos::new(x, z)];
+//!     town
+//! };
+//! let towns = [town("Near", 2, 0), town("Far", 100, 0)];
+//! let nearby = Nearby::new(&towns);
+//!
+//! let origin = NearbyOrigin::Coordinates(BlockPosXZ::new(0, 0));
+//! let found = nearby.towns(origin, 500.0, DistanceTo::HomeBlock);
+//! assert_eq!(found.len(), 1);
+//! assert_eq!(found[0].town.name, "Near");
+//! assert_eq!(found[0].distance, 32.0);
+//! ```
+use std::collections::HashMap;
+
+use crate::{
+    id::NationId,
+    named_id::NamedId,
+    nation::Nation,
+    position::{BlockPosXZ, CHUNK_SIZE, ChunkPos},
+    town::Town,
+};
+
+/// Where a nearby search is measured from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NearbyOrigin<'a> {
+    /// A town's home block, like the endpoint's `TOWN` target. The town
+    /// itself is left out of the results.
+    Town(&'a Town),
+    /// A block, like the endpoint's `COORDINATE` target.
+    Coordinates(BlockPosXZ),
+}
+
+impl NearbyOrigin<'_> {
+    fn point(&self) -> (f64, f64) {
+        match self {
+            NearbyOrigin::Town(town) => home_block(town),
+            NearbyOrigin::Coordinates(block) => {
+                (block.x as f64, block.z as f64)
+            }
+        }
+    }
+}
+
+/// Which part of a town distances are measured to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DistanceTo {
+    /// The north-west corner of the town's home block. This is assumed to be
+    /// what the `nearby` endpoint measures to, but it hasn't been verified.
+    #[default]
+    HomeBlock,
+    /// The town's spawn.
+    Spawn,
+    /// The closest edge of any claimed chunk, or zero when the origin is
+    /// inside the town.
+    NearestChunk,
+}
+
+/// What a [`NearbySearch`] is measured from, by value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NearbySearchTarget {
+    /// A town, by name.
+    Town(String),
+    Coordinates(BlockPosXZ),
+}
+
+/// A search for towns, in the shape of one item of a `nearby` endpoint
+/// query. The `earthmc` crate converts its `NearbyQueryItem` into this.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearbySearch {
+    pub target: NearbySearchTarget,
+    /// The radius in blocks.
+    pub radius: f64,
+    pub to: DistanceTo,
+}
+
+/// A town found by a nearby search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NearbyTown<'a> {
+    pub town: &'a Town,
+    /// The distance in blocks.
+    pub distance: f64,
+}
+
+/// A nation found by a nearby search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearbyNation<'a> {
+    pub nation: NamedId<Nation>,
+    /// The nation's closest town.
+    pub closest: &'a Town,
+    /// The distance to the closest town in blocks.
+    pub distance: f64,
+}
+
+/// Searches a set of towns by distance.
+#[derive(Debug, Clone, Default)]
+pub struct Nearby<'a> {
+    towns: Vec<&'a Town>,
+}
+
+impl<'a> Nearby<'a> {
+    pub fn new(towns: impl IntoIterator<Item = &'a Town>) -> Self {
+        Self {
+            towns: towns.into_iter().collect(),
+        }
+    }
+
+    /// Finds a town by name, ignoring case like the API does.
+    pub fn town(&self, name: &str) -> Option<&'a Town> {
+        self.towns
+            .iter()
+            .copied()
+            .find(|town| town.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The towns within `radius` blocks of `origin`, closest first.
+    pub fn towns(
+        &self,
+        origin: NearbyOrigin<'_>,
+        radius: f64,
+        to: DistanceTo,
+    ) -> Vec<NearbyTown<'a>> {
+        self.search(origin, radius, to, |_| true)
+    }
+
+    /// Runs `search` for towns, closest first. Returns `None` if it targets
+    /// a town that isn't in the set.
+    pub fn query(&self, search: &NearbySearch) -> Option<Vec<NearbyTown<'a>>> {
+        let origin = match &search.target {
+            NearbySearchTarget::Town(name) => {
+                NearbyOrigin::Town(self.town(name)?)
+            }
+            NearbySearchTarget::Coordinates(block) => {
+                NearbyOrigin::Coordinates(*block)
+            }
+        };
+        Some(self.towns(origin, search.radius, search.to))
+    }
+
+    /// The capitals within `radius` blocks of `origin`, closest first.
+    pub fn capitals(
+        &self,
+        origin: NearbyOrigin<'_>,
+        radius: f64,
+        to: DistanceTo,
+    ) -> Vec<NearbyTown<'a>> {
+        self.search(origin, radius, to, |town| town.status.is_capital)
+    }
+
+    /// The nations with a town within `radius` blocks of `origin`, closest
+    /// first. A nation's distance is that of its closest town.
+    pub fn nations(
+        &self,
+        origin: NearbyOrigin<'_>,
+        radius: f64,
+        to: DistanceTo,
+    ) -> Vec<NearbyNation<'a>> {
+        let mut nations: HashMap<NationId, NearbyNation<'a>> = HashMap::new();
+        let towns =
+            self.search(origin, radius, to, |town| town.nation.uuid.is_some());
+
+        // towns are sorted, so the first town seen is the closest
+        let mut order = Vec::new();
+        for NearbyTown { town, distance } in towns {
+            let (Some(name), Some(uuid)) =
+                (&town.nation.name, town.nation.uuid)
+            else {
+                continue;
+            };
+            nations.entry(uuid).or_insert_with(|| {
+                order.push(uuid);
+                NearbyNation {
+                    nation: NamedId {
+                        name: name.clone(),
+                        uuid,
+                    },
+                    closest: town,
+                    distance,
+                }
+            });
+        }
+        order
+            .into_iter()
+            .filter_map(|uuid| nations.remove(&uuid))
+            .collect()
+    }
+
+    fn search(
+        &self,
+        origin: NearbyOrigin<'_>,
+        radius: f64,
+        to: DistanceTo,
+        filter: impl Fn(&Town) -> bool,
+    ) -> Vec<NearbyTown<'a>> {
+        let point = origin.point();
+        let excluded = match origin {
+            NearbyOrigin::Town(town) => Some(town.uuid),
+            NearbyOrigin::Coordinates(_) => None,
+        };
+
+        let mut found: Vec<NearbyTown<'a>> = self
+            .towns
+            .iter()
+            .copied()
+            .filter(|town| Some(town.uuid) != excluded && filter(town))
+            .filter_map(|town| {
+                let distance = distance(point, town, to)?;
+                (distance <= radius).then_some(NearbyTown { town, distance })
+            })
+            .collect();
+        found.sort_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then_with(|| a.town.name.cmp(&b.town.name))
+        });
+        found
+    }
+}
+
+/// The distance from `point` to `town`. Returns `None` for
+/// [`DistanceTo::NearestChunk`] if the town has no claims.
+fn distance(point: (f64, f64), town: &Town, to: DistanceTo) -> Option<f64> {
+    let target = match to {
+        DistanceTo::HomeBlock => home_block(town),
+        DistanceTo::Spawn => {
+            let spawn = &town.coordinates.spawn;
+            (spawn.x as f64, spawn.z as f64)
+        }
+        DistanceTo::NearestChunk => {
+            return town
+                .coordinates
+                .town_blocks
+                .iter()
+                .map(|&chunk| distance_to_chunk(point, chunk))
+                .min_by(f64::total_cmp);
+        }
+    };
+    Some((point.0 - target.0).hypot(point.1 - target.1))
+}
+
+fn home_block(town: &Town) -> (f64, f64) {
+    let corner = town.coordinates.home_block.min_block();
+    (corner.x as f64, corner.z as f64)
+}
+
+fn distance_to_chunk(point: (f64, f64), chunk: ChunkPos) -> f64 {
+    let min = chunk.min_block();
+    let (min_x, min_z) = (min.x as f64, min.z as f64);
+    let size = CHUNK_SIZE as f64;
+    let dx = (min_x - point.0).max(point.0 - (min_x + size)).max(0.0);
+    let dz = (min_z - point.1).max(point.1 - (min_z + size)).max(0.0);
+    dx.hypot(dz)
+}
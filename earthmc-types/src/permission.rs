@@ -0,0 +1,655 @@
+//! # Permission
+//!
+//! Defines the [`TownyPermissions`] struct, along with the [`TownyAction`]s
+//! and [`TownyPermissionLevel`]s it grants permissions for.
+//!
+//! Besides the API's JSON format, the permission types can be displayed and
+//! parsed in the text format of the in-game `/t perm` command.
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, IgnoredAny, SeqAccess, Visitor},
+    ser::SerializeTuple,
+};
+use std::{
+    error::Error,
+    fmt::{self, Formatter, Write},
+    str::FromStr,
+};
+
+/// Permission flags for each type of player in a given [`Town`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "ts-rs",
+    derive(ts_rs::TS),
+    ts(type = "[boolean, boolean, boolean, boolean]")
+)]
+pub struct TownyPermissionSet {
+    pub resident: bool,
+    pub nation: bool,
+    pub ally: bool,
+    pub outsider: bool,
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct TownyPermissions {
+    pub build: TownyPermissionSet,
+    pub destroy: TownyPermissionSet,
+    pub switch: TownyPermissionSet,
+    pub item_use: TownyPermissionSet,
+    pub flags: TownyPermissionFlags,
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct TownyPermissionFlags {
+    pub pvp: bool,
+    pub explosion: bool,
+    pub fire: bool,
+    pub mobs: bool,
+}
+
+/// An action guarded by a [`TownyPermissionSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TownyAction {
+    Build,
+    Destroy,
+    Switch,
+    ItemUse,
+}
+
+impl TownyAction {
+    /// Every action, in the order the API lists them.
+    pub const ALL: [TownyAction; 4] = [
+        TownyAction::Build,
+        TownyAction::Destroy,
+        TownyAction::Switch,
+        TownyAction::ItemUse,
+    ];
+
+    /// The name `/t perm` shows, e.g. `"ItemUse"`.
+    pub const fn label(self) -> &'static str {
+        match self {
+            TownyAction::Build => "Build",
+            TownyAction::Destroy => "Destroy",
+            TownyAction::Switch => "Switch",
+            TownyAction::ItemUse => "ItemUse",
+        }
+    }
+
+    /// Looks up an action by its label, ignoring case, spaces and
+    /// underscores, so `"item_use"` is [`TownyAction::ItemUse`].
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = normalize_label(label);
+        Self::ALL
+            .into_iter()
+            .find(|action| normalize_label(action.label()) == label)
+    }
+}
+
+/// The group of players a [`TownyPermissionSet`] flag applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TownyPermissionLevel {
+    /// Residents of the town.
+    Resident,
+    /// Residents of other towns in the same nation.
+    Nation,
+    /// Residents of allied nations.
+    Ally,
+    /// Everyone else.
+    Outsider,
+}
+
+impl TownyPermissionLevel {
+    /// Every level, in the order the API lists them.
+    pub const ALL: [TownyPermissionLevel; 4] = [
+        TownyPermissionLevel::Resident,
+        TownyPermissionLevel::Nation,
+        TownyPermissionLevel::Ally,
+        TownyPermissionLevel::Outsider,
+    ];
+
+    /// The letter `/t perm` uses for this level, e.g. `'r'` for residents.
+    pub const fn letter(self) -> char {
+        match self {
+            TownyPermissionLevel::Resident => 'r',
+            TownyPermissionLevel::Nation => 'n',
+            TownyPermissionLevel::Ally => 'a',
+            TownyPermissionLevel::Outsider => 'o',
+        }
+    }
+
+    /// Looks up a level by its letter, ignoring case.
+    pub fn from_letter(letter: char) -> Option<Self> {
+        let letter = letter.to_ascii_lowercase();
+        Self::ALL.into_iter().find(|level| level.letter() == letter)
+    }
+
+    /// A plural description of the players at this level, e.g. `"allies"`.
+    pub const fn label(self) -> &'static str {
+        match self {
+            TownyPermissionLevel::Resident => "residents",
+            TownyPermissionLevel::Nation => "nation members",
+            TownyPermissionLevel::Ally => "allies",
+            TownyPermissionLevel::Outsider => "outsiders",
+        }
+    }
+}
+
+/// A toggle in [`TownyPermissionFlags`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TownyFlag {
+    Pvp,
+    Explosion,
+    Fire,
+    Mobs,
+}
+
+impl TownyFlag {
+    /// Every flag, in the order the API lists them.
+    pub const ALL: [TownyFlag; 4] = [
+        TownyFlag::Pvp,
+        TownyFlag::Explosion,
+        TownyFlag::Fire,
+        TownyFlag::Mobs,
+    ];
+
+    /// The name `/t perm` shows, e.g. `"Mob Spawns"`.
+    pub const fn label(self) -> &'static str {
+        match self {
+            TownyFlag::Pvp => "PvP",
+            TownyFlag::Explosion => "Explosions",
+            TownyFlag::Fire => "Firespread",
+            TownyFlag::Mobs => "Mob Spawns",
+        }
+    }
+
+    /// The field name the API uses, e.g. `"mobs"`.
+    pub const fn key(self) -> &'static str {
+        match self {
+            TownyFlag::Pvp => "pvp",
+            TownyFlag::Explosion => "explosion",
+            TownyFlag::Fire => "fire",
+            TownyFlag::Mobs => "mobs",
+        }
+    }
+
+    /// Looks up a flag by its label or API key, ignoring case, spaces and
+    /// underscores.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = normalize_label(label);
+        Self::ALL.into_iter().find(|flag| {
+            normalize_label(flag.label()) == label || flag.key() == label
+        })
+    }
+}
+
+/// An error from parsing the `/t perm` text format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePermissionError {
+    /// A character other than `r`, `n`, `a`, `o` or `-` in a permission set.
+    InvalidLevel(char),
+    /// A permission level given more than once in a permission set.
+    DuplicateLevel(char),
+    UnknownAction(String),
+    UnknownFlag(String),
+    /// A flag value other than `ON` or `OFF`.
+    InvalidFlagValue(String),
+    /// Text that isn't part of an `Action = rnao` or `Flag: ON` entry.
+    UnexpectedText(String),
+    /// An action or flag, by label, that wasn't given.
+    Missing(&'static str),
+    /// An action or flag, by label, given more than once.
+    Duplicate(&'static str),
+}
+
+impl fmt::Display for ParsePermissionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLevel(c) => {
+                write!(
+                    f,
+                    "invalid permission level {c:?}, expected one of rnao"
+                )
+            }
+            Self::DuplicateLevel(c) => {
+                write!(f, "permission level {c:?} given more than once")
+            }
+            Self::UnknownAction(action) => {
+                write!(f, "unknown permission {action:?}")
+            }
+            Self::UnknownFlag(flag) => write!(f, "unknown flag {flag:?}"),
+            Self::InvalidFlagValue(value) => {
+                write!(f, "invalid flag value {value:?}, expected ON or OFF")
+            }
+            Self::UnexpectedText(text) => {
+                write!(f, "unexpected text {text:?}")
+            }
+            Self::Missing(label) => write!(f, "missing {label}"),
+            Self::Duplicate(label) => {
+                write!(f, "{label} given more than once")
+            }
+        }
+    }
+}
+
+impl Error for ParsePermissionError {}
+
+/// A single difference between two [`TownyPermissions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PermissionChange {
+    /// Players at `level` were allowed or denied `action`.
+    Permission {
+        action: TownyAction,
+        level: TownyPermissionLevel,
+        allowed: bool,
+    },
+    /// A flag was turned on or off.
+    Flag { flag: TownyFlag, enabled: bool },
+}
+
+impl fmt::Display for PermissionChange {
+    /// Formats as e.g. `Build: allowed for allies` or `PvP: ON`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Permission {
+                action,
+                level,
+                allowed,
+            } => write!(
+                f,
+                "{}: {} for {}",
+                action.label(),
+                if *allowed { "allowed" } else { "denied" },
+                level.label()
+            ),
+            Self::Flag { flag, enabled } => {
+                write!(f, "{}: {}", flag.label(), on_off(*enabled))
+            }
+        }
+    }
+}
+
+impl TownyPermissionSet {
+    /// Whether players at `level` have this permission.
+    pub fn get(&self, level: TownyPermissionLevel) -> bool {
+        match level {
+            TownyPermissionLevel::Resident => self.resident,
+            TownyPermissionLevel::Nation => self.nation,
+            TownyPermissionLevel::Ally => self.ally,
+            TownyPermissionLevel::Outsider => self.outsider,
+        }
+    }
+
+    pub fn set(&mut self, level: TownyPermissionLevel, allowed: bool) {
+        match level {
+            TownyPermissionLevel::Resident => self.resident = allowed,
+            TownyPermissionLevel::Nation => self.nation = allowed,
+            TownyPermissionLevel::Ally => self.ally = allowed,
+            TownyPermissionLevel::Outsider => self.outsider = allowed,
+        }
+    }
+
+    /// The levels whose permission differs in `other`, with their value
+    /// there.
+    pub fn diff(
+        &self,
+        other: &Self,
+    ) -> impl Iterator<Item = (TownyPermissionLevel, bool)> + use<> {
+        let (this, other) = (*self, *other);
+        TownyPermissionLevel::ALL
+            .into_iter()
+            .filter(move |&level| this.get(level) != other.get(level))
+            .map(move |level| (level, other.get(level)))
+    }
+}
+
+impl TownyPermissionFlags {
+    pub fn get(&self, flag: TownyFlag) -> bool {
+        match flag {
+            TownyFlag::Pvp => self.pvp,
+            TownyFlag::Explosion => self.explosion,
+            TownyFlag::Fire => self.fire,
+            TownyFlag::Mobs => self.mobs,
+        }
+    }
+
+    pub fn set(&mut self, flag: TownyFlag, enabled: bool) {
+        match flag {
+            TownyFlag::Pvp => self.pvp = enabled,
+            TownyFlag::Explosion => self.explosion = enabled,
+            TownyFlag::Fire => self.fire = enabled,
+            TownyFlag::Mobs => self.mobs = enabled,
+        }
+    }
+}
+
+impl TownyPermissions {
+    /// The permission set for `action`.
+    pub fn get(&self, action: TownyAction) -> TownyPermissionSet {
+        match action {
+            TownyAction::Build => self.build,
+            TownyAction::Destroy => self.destroy,
+            TownyAction::Switch => self.switch,
+            TownyAction::ItemUse => self.item_use,
+        }
+    }
+
+    pub fn set(&mut self, action: TownyAction, set: TownyPermissionSet) {
+        match action {
+            TownyAction::Build => self.build = set,
+            TownyAction::Destroy => self.destroy = set,
+            TownyAction::Switch => self.switch = set,
+            TownyAction::ItemUse => self.item_use = set,
+        }
+    }
+
+    /// The changes that turn these permissions into `other`, with
+    /// permissions before flags, each in the order the API lists them.
+    pub fn diff(&self, other: &Self) -> Vec<PermissionChange> {
+        let permissions = TownyAction::ALL.into_iter().flat_map(|action| {
+            self.get(action).diff(&other.get(action)).map(
+                move |(level, allowed)| PermissionChange::Permission {
+                    action,
+                    level,
+                    allowed,
+                },
+            )
+        });
+        let flags = TownyFlag::ALL
+            .into_iter()
+            .filter(|&flag| self.flags.get(flag) != other.flags.get(flag))
+            .map(|flag| PermissionChange::Flag {
+                flag,
+                enabled: other.flags.get(flag),
+            });
+        permissions.chain(flags).collect()
+    }
+}
+
+impl fmt::Display for TownyPermissionSet {
+    /// Formats as in `/t perm`, with the letter of each allowed level and
+    /// `-` for the others, e.g. `rn--`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for level in TownyPermissionLevel::ALL {
+            let c = if self.get(level) { level.letter() } else { '-' };
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TownyPermissionSet {
+    type Err = ParsePermissionError;
+
+    /// Parses the letters of the allowed levels in any order, ignoring `-`,
+    /// so both `rn--` and `nr` allow residents and nation members.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::default();
+        for c in s.trim().chars().filter(|&c| c != '-') {
+            let level = TownyPermissionLevel::from_letter(c)
+                .ok_or(ParsePermissionError::InvalidLevel(c))?;
+            if set.get(level) {
+                return Err(ParsePermissionError::DuplicateLevel(c));
+            }
+            set.set(level, true);
+        }
+        Ok(set)
+    }
+}
+
+impl fmt::Display for TownyPermissionFlags {
+    /// Formats as in `/t perm`, e.g.
+    /// `PvP: OFF  Explosions: OFF  Firespread: OFF  Mob Spawns: ON`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, flag) in TownyFlag::ALL.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str("  ")?;
+            }
+            write!(f, "{}: {}", flag.label(), on_off(self.get(flag)))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TownyPermissionFlags {
+    type Err = ParsePermissionError;
+
+    /// Parses `Flag: ON` entries separated by whitespace, in any order. Every
+    /// flag must be given exactly once.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let spaced = s.replace(':', ": ");
+        let mut tokens = spaced.split_whitespace();
+        let mut flags = Self::default();
+        let mut seen = [false; 4];
+        let mut label = Vec::new();
+
+        while let Some(token) = tokens.next() {
+            let Some(word) = token.strip_suffix(':') else {
+                label.push(token);
+                continue;
+            };
+            label.push(word);
+            let name = label.join(" ");
+            label.clear();
+
+            let flag = TownyFlag::from_label(&name)
+                .ok_or(ParsePermissionError::UnknownFlag(name))?;
+            let value = tokens.next().unwrap_or_default();
+            let enabled = if value.eq_ignore_ascii_case("on") {
+                true
+            } else if value.eq_ignore_ascii_case("off") {
+                false
+            } else {
+                return Err(ParsePermissionError::InvalidFlagValue(
+                    value.to_owned(),
+                ));
+            };
+
+            if std::mem::replace(&mut seen[flag as usize], true) {
+                return Err(ParsePermissionError::Duplicate(flag.label()));
+            }
+            flags.set(flag, enabled);
+        }
+
+        if !label.is_empty() {
+            return Err(ParsePermissionError::UnexpectedText(label.join(" ")));
+        }
+        match TownyFlag::ALL
+            .into_iter()
+            .find(|&flag| !seen[flag as usize])
+        {
+            Some(flag) => Err(ParsePermissionError::Missing(flag.label())),
+            None => Ok(flags),
+        }
+    }
+}
+
+impl fmt::Display for TownyPermissions {
+    /// Formats as in `/t perm`, with one `Action = rnao` line per action
+    /// followed by the flags, e.g.
+    ///
+    /// ```text
+    /// Build = r---
+    /// Destroy = r---
+    /// Switch = rna-
+    /// ItemUse = rna-
+    /// PvP: OFF  Explosions: OFF  Firespread: OFF  Mob Spawns: OFF
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for action in TownyAction::ALL {
+            writeln!(f, "{} = {}", action.label(), self.get(action))?;
+        }
+        write!(f, "{}", self.flags)
+    }
+}
+
+impl FromStr for TownyPermissions {
+    type Err = ParsePermissionError;
+
+    /// Parses the [`Display`](fmt::Display) format, or the game's layout
+    /// with several `Action = rnao` entries on one line, optionally after a
+    /// label like `Perms:`. Entries may come in any order and labels ignore
+    /// case, but every action and flag must be given exactly once.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut perms = Self::default();
+        let mut seen = [false; 4];
+        let mut flags = String::new();
+
+        for line in s.lines() {
+            if !line.contains('=') {
+                flags.push_str(line);
+                flags.push('\n');
+                continue;
+            }
+            let spaced = line.replace('=', " = ");
+            let mut tokens = spaced.split_whitespace().peekable();
+            tokens.next_if(|token| token.ends_with(':'));
+            let mut name = Vec::new();
+
+            while let Some(token) = tokens.next() {
+                if token != "=" {
+                    name.push(token);
+                    continue;
+                }
+                let label = name.join(" ");
+                name.clear();
+
+                let action = TownyAction::from_label(&label)
+                    .ok_or(ParsePermissionError::UnknownAction(label))?;
+                if std::mem::replace(&mut seen[action as usize], true) {
+                    return Err(ParsePermissionError::Duplicate(
+                        action.label(),
+                    ));
+                }
+                perms.set(action, tokens.next().unwrap_or_default().parse()?);
+            }
+
+            if !name.is_empty() {
+                return Err(ParsePermissionError::UnexpectedText(
+                    name.join(" "),
+                ));
+            }
+        }
+
+        if let Some(action) =
+            TownyAction::ALL.into_iter().find(|&a| !seen[a as usize])
+        {
+            return Err(ParsePermissionError::Missing(action.label()));
+        }
+        perms.flags = flags.parse()?;
+        Ok(perms)
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled { "ON" } else { "OFF" }
+}
+
+/// Lowercases `label` and strips spaces and underscores, so labels can be
+/// compared loosely.
+fn normalize_label(label: &str) -> String {
+    label
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+impl Serialize for TownyPermissionSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_tuple(4)?;
+        seq.serialize_element(&self.resident)?;
+        seq.serialize_element(&self.nation)?;
+        seq.serialize_element(&self.ally)?;
+        seq.serialize_element(&self.outsider)?;
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TownyPermissionSet {
+    fn deserialize<D>(deserializer: D) -> Result<TownyPermissionSet, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PermVisitor;
+
+        impl<'de> Visitor<'de> for PermVisitor {
+            type Value = TownyPermissionSet;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("an array of exactly four booleans")
+            }
+
+            fn visit_seq<A>(
+                self,
+                mut seq: A,
+            ) -> Result<TownyPermissionSet, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                // pull out each boolean in order; if the array has fewer
+                // than 4 items, error.
+                let resident = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let nation = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let ally = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let outsider = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+
+                // if there are extra elements beyond the fourth, that’s also
+                // an error
+                if (seq.next_element::<IgnoredAny>()?).is_some() {
+                    return Err(de::Error::invalid_length(
+                        5,
+                        &"expected exactly four booleans",
+                    ));
+                }
+
+                Ok(TownyPermissionSet {
+                    resident,
+                    nation,
+                    ally,
+                    outsider,
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(4, PermVisitor)
+    }
+}
+
+/// Serializes as an array of four booleans, like the API.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for TownyPermissionSet {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "TownyPermissionSet".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Whether residents, nation members, allies and outsiders have the permission, in that order.",
+            "type": "array",
+            "items": { "type": "boolean" },
+            "minItems": 4,
+            "maxItems": 4,
+        })
+    }
+}
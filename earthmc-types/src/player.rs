@@ -1,23 +1,28 @@
 //! # Player
 //!
 //! Defines the [`Player`] struct.
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 use crate::{
+    id::PlayerId,
     named_id::{NamedId, NamedIdOpt},
-    nation::NationRankKind,
+    nation::{Nation, NationRankKind},
     permission::TownyPermissions,
-    town::TownRankKind,
+    timestamp::Timestamp,
+    town::{Town, TownRankKind},
 };
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct Player {
     /// The player's name.
     pub name: String,
-    /// The player's [`Uuid`].
-    pub uuid: Uuid,
+    /// The player's ID.
+    pub uuid: PlayerId,
     /// The player's title set through `/n set title` in-game.
     pub title: Option<String>,
     /// The player's title set through `/n set surname` in-game.
@@ -27,9 +32,9 @@ pub struct Player {
     /// About section of `/res` set with `/res set about` in-game.
     pub about: Option<String>,
     /// The player's town.
-    pub town: NamedIdOpt,
+    pub town: NamedIdOpt<Town>,
     /// The nation that the player's town is a part of.
-    pub nation: NamedIdOpt,
+    pub nation: NamedIdOpt<Nation>,
     pub timestamps: PlayerTimestamps,
     pub status: PlayerStatus,
     pub stats: PlayerStats,
@@ -37,22 +42,43 @@ pub struct Player {
     /// The ranks that the player has in both their town and nation.
     pub ranks: PlayerRanks,
     /// List of the player's friends.
-    pub friends: Vec<NamedId>,
+    pub friends: Vec<NamedId<Player>>,
+}
+
+impl Player {
+    /// The time elapsed since the player was last online.
+    ///
+    /// [`None`] if the player is currently online, or has never been online
+    /// (e.g. NPCs).
+    pub fn offline_for(&self) -> Option<Duration> {
+        if self.status.is_online {
+            return None;
+        }
+        self.timestamps.last_online.map(|last| last.elapsed())
+    }
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerTimestamps {
-    /// Unix timestamp representing when the player joined the server.
-    pub registered: i64,
-    /// Unix timestamp representing when the player joined their town.
-    pub joined_town_at: Option<i64>,
-    /// Unix timestamp representing when the player was last online.
+    /// When the player joined the server.
+    pub registered: Timestamp,
+    /// When the player joined their town.
+    pub joined_town_at: Option<Timestamp>,
+    /// When the player was last online.
     /// Can be [`None`] if the player is an NPC.
-    pub last_online: Option<i64>,
+    pub last_online: Option<Timestamp>,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerStatus {
     /// If the player is currently online.
@@ -70,7 +96,9 @@ pub struct PlayerStatus {
     pub has_nation: bool,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerStats {
     /// The player's current balance as seen on `/res` in-game.
@@ -79,7 +107,11 @@ pub struct PlayerStats {
     pub num_friends: i32,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct PlayerRanks {
     /// A list of town ranks the player holds.
@@ -0,0 +1,664 @@
+//! # Player Stats
+//!
+//! Defines the [`StatMap`] struct, which holds the statistics of every player
+//! on the server added together, and the [`StatKind`] enum of statistics.
+use std::{borrow::Cow, collections::BTreeMap, fmt, time::Duration};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The number of game ticks in a second.
+pub const TICKS_PER_SECOND: u64 = 20;
+/// The number of centimetres in a block.
+pub const CM_PER_BLOCK: f64 = 100.0;
+/// The number of centimetres in a kilometre.
+pub const CM_PER_KM: f64 = 100_000.0;
+/// Damage statistics are recorded in tenths of a health point, and a heart is
+/// two health points.
+pub const DAMAGE_PER_HEART: f64 = 20.0;
+
+macro_rules! stat_kinds {
+    (
+        $($key:literal => $variant:ident($label:literal, $category:ident)),*
+        $(,)?
+    ) => {
+        /// A statistic known to this crate, ordered by its API key.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum StatKind {
+            $($variant,)*
+        }
+
+        impl StatKind {
+            /// Every known statistic, ordered by its API key.
+            pub const ALL: &'static [StatKind] = &[$(StatKind::$variant,)*];
+            /// The number of known statistics.
+            pub const COUNT: usize = Self::ALL.len();
+
+            /// The key the API uses for this statistic, e.g. `"walk_one_cm"`.
+            pub const fn key(&self) -> &'static str {
+                match self {
+                    $(StatKind::$variant => $key,)*
+                }
+            }
+
+            /// Looks up a statistic by its API key.
+            pub fn from_key(key: &str) -> Option<Self> {
+                match key {
+                    $($key => Some(StatKind::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// A human-readable name for the statistic, e.g.
+            /// `"Distance Walked"`.
+            pub const fn label(&self) -> &'static str {
+                match self {
+                    $(StatKind::$variant => $label,)*
+                }
+            }
+
+            /// The category the statistic belongs to.
+            pub const fn category(&self) -> StatCategory {
+                match self {
+                    $(StatKind::$variant => StatCategory::$category,)*
+                }
+            }
+        }
+    };
+}
+
+stat_kinds! {
+    "animals_bred" => AnimalsBred("Animals Bred", Misc),
+    "aviate_one_cm" => AviateOneCm("Distance by Elytra", Movement),
+    "bell_ring" => BellRing("Bells Rung", Interaction),
+    "boat_one_cm" => BoatOneCm("Distance by Boat", Movement),
+    "clean_armor" => CleanArmor("Armor Pieces Cleaned", Interaction),
+    "clean_banner" => CleanBanner("Banners Cleaned", Interaction),
+    "clean_shulker_box" => CleanShulkerBox(
+        "Shulker Boxes Cleaned", Interaction
+    ),
+    "climb_one_cm" => ClimbOneCm("Distance Climbed", Movement),
+    "crouch_one_cm" => CrouchOneCm("Distance Crouched", Movement),
+    "damage_absorbed" => DamageAbsorbed("Damage Absorbed", Combat),
+    "damage_blocked_by_shield" => DamageBlockedByShield(
+        "Damage Blocked by Shield", Combat
+    ),
+    "damage_dealt" => DamageDealt("Damage Dealt", Combat),
+    "damage_dealt_absorbed" => DamageDealtAbsorbed(
+        "Damage Dealt (Absorbed)", Combat
+    ),
+    "damage_dealt_resisted" => DamageDealtResisted(
+        "Damage Dealt (Resisted)", Combat
+    ),
+    "damage_resisted" => DamageResisted("Damage Resisted", Combat),
+    "damage_taken" => DamageTaken("Damage Taken", Combat),
+    "deaths" => Deaths("Deaths", Combat),
+    "drop_count" => DropCount("Items Dropped", Misc),
+    "eat_cake_slice" => EatCakeSlice("Cake Slices Eaten", Interaction),
+    "enchant_item" => EnchantItem("Items Enchanted", Interaction),
+    "fall_one_cm" => FallOneCm("Distance Fallen", Movement),
+    "fill_cauldron" => FillCauldron("Cauldrons Filled", Interaction),
+    "fish_caught" => FishCaught("Fish Caught", Misc),
+    "fly_one_cm" => FlyOneCm("Distance Flown", Movement),
+    "horse_one_cm" => HorseOneCm("Distance by Horse", Movement),
+    "inspect_dispenser" => InspectDispenser("Dispensers Searched", Redstone),
+    "inspect_dropper" => InspectDropper("Droppers Searched", Redstone),
+    "inspect_hopper" => InspectHopper("Hoppers Searched", Redstone),
+    "interact_with_anvil" => InteractWithAnvil(
+        "Interactions with Anvil", Interaction
+    ),
+    "interact_with_beacon" => InteractWithBeacon(
+        "Interactions with Beacon", Interaction
+    ),
+    "interact_with_blast_furnace" => InteractWithBlastFurnace(
+        "Interactions with Blast Furnace", Interaction
+    ),
+    "interact_with_brewingstand" => InteractWithBrewingstand(
+        "Interactions with Brewing Stand", Interaction
+    ),
+    "interact_with_campfire" => InteractWithCampfire(
+        "Interactions with Campfire", Interaction
+    ),
+    "interact_with_cartography_table" => InteractWithCartographyTable(
+        "Interactions with Cartography Table", Interaction
+    ),
+    "interact_with_crafting_table" => InteractWithCraftingTable(
+        "Interactions with Crafting Table", Interaction
+    ),
+    "interact_with_furnace" => InteractWithFurnace(
+        "Interactions with Furnace", Interaction
+    ),
+    "interact_with_grindstone" => InteractWithGrindstone(
+        "Interactions with Grindstone", Interaction
+    ),
+    "interact_with_lectern" => InteractWithLectern(
+        "Interactions with Lectern", Interaction
+    ),
+    "interact_with_loom" => InteractWithLoom(
+        "Interactions with Loom", Interaction
+    ),
+    "interact_with_smithing_table" => InteractWithSmithingTable(
+        "Interactions with Smithing Table", Interaction
+    ),
+    "interact_with_smoker" => InteractWithSmoker(
+        "Interactions with Smoker", Interaction
+    ),
+    "interact_with_stonecutter" => InteractWithStonecutter(
+        "Interactions with Stonecutter", Interaction
+    ),
+    "jump" => Jump("Jumps", Movement),
+    "leave_game" => LeaveGame("Games Quit", Misc),
+    "minecart_one_cm" => MinecartOneCm("Distance by Minecart", Movement),
+    "mob_kills" => MobKills("Mob Kills", Combat),
+    "open_barrel" => OpenBarrel("Barrels Opened", Interaction),
+    "open_chest" => OpenChest("Chests Opened", Interaction),
+    "open_enderchest" => OpenEnderchest("Ender Chests Opened", Interaction),
+    "open_shulker_box" => OpenShulkerBox("Shulker Boxes Opened", Interaction),
+    "pig_one_cm" => PigOneCm("Distance by Pig", Movement),
+    "play_noteblock" => PlayNoteblock("Note Blocks Played", Redstone),
+    "play_record" => PlayRecord("Music Discs Played", Interaction),
+    "play_time" => PlayTime("Time Played", Misc),
+    "player_kills" => PlayerKills("Player Kills", Combat),
+    "pot_flower" => PotFlower("Plants Potted", Interaction),
+    "raid_trigger" => RaidTrigger("Raids Triggered", Combat),
+    "raid_win" => RaidWin("Raids Won", Combat),
+    "sleep_in_bed" => SleepInBed("Times Slept in a Bed", Interaction),
+    "sneak_time" => SneakTime("Sneak Time", Misc),
+    "sprint_one_cm" => SprintOneCm("Distance Sprinted", Movement),
+    "strider_one_cm" => StriderOneCm("Distance by Strider", Movement),
+    "swim_one_cm" => SwimOneCm("Distance Swum", Movement),
+    "talked_to_villager" => TalkedToVillager(
+        "Talked to Villagers", Interaction
+    ),
+    "target_hit" => TargetHit("Targets Hit", Redstone),
+    "time_since_death" => TimeSinceDeath("Time Since Last Death", Misc),
+    "time_since_rest" => TimeSinceRest("Time Since Last Rest", Misc),
+    "total_world_time" => TotalWorldTime("Time with World Open", Misc),
+    "traded_with_villager" => TradedWithVillager(
+        "Traded with Villagers", Interaction
+    ),
+    "trigger_trapped_chest" => TriggerTrappedChest(
+        "Trapped Chests Triggered", Redstone
+    ),
+    "tune_noteblock" => TuneNoteblock("Note Blocks Tuned", Redstone),
+    "use_cauldron" => UseCauldron("Water Taken from Cauldron", Interaction),
+    "walk_on_water_one_cm" => WalkOnWaterOneCm(
+        "Distance Walked on Water", Movement
+    ),
+    "walk_one_cm" => WalkOneCm("Distance Walked", Movement),
+    "walk_under_water_one_cm" => WalkUnderWaterOneCm(
+        "Distance Walked under Water", Movement
+    ),
+}
+
+impl StatKind {
+    /// The unit the statistic is measured in.
+    pub fn unit(&self) -> StatUnit {
+        if self.key().ends_with("_one_cm") {
+            StatUnit::Distance
+        } else if self.key().starts_with("damage_") {
+            StatUnit::Damage
+        } else if matches!(
+            self,
+            StatKind::PlayTime
+                | StatKind::SneakTime
+                | StatKind::TimeSinceDeath
+                | StatKind::TimeSinceRest
+                | StatKind::TotalWorldTime
+        ) {
+            StatUnit::Time
+        } else {
+            StatUnit::Count
+        }
+    }
+
+    /// Whether the statistic is a distance in centimetres, like
+    /// [`StatKind::WalkOneCm`].
+    pub fn is_distance(&self) -> bool {
+        self.unit() == StatUnit::Distance
+    }
+
+    /// Whether the statistic is a length of time in ticks, like
+    /// [`StatKind::PlayTime`].
+    pub fn is_time(&self) -> bool {
+        self.unit() == StatUnit::Time
+    }
+
+    /// Whether the statistic is an amount of damage in tenths of a health
+    /// point, like [`StatKind::DamageDealt`].
+    pub fn is_damage(&self) -> bool {
+        self.unit() == StatUnit::Damage
+    }
+
+    /// Formats a value of this statistic in its unit, e.g. `"1,234 km"`.
+    pub fn format_value(&self, value: i64) -> String {
+        self.unit().format(value)
+    }
+}
+
+impl fmt::Display for StatKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// The key of a statistic in a [`StatMap`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stat {
+    Known(StatKind),
+    /// A statistic this crate doesn't know about, with its API key.
+    Other(String),
+}
+
+impl Stat {
+    /// Looks up a statistic by its API key, falling back to [`Stat::Other`].
+    pub fn from_key(key: &str) -> Self {
+        StatKind::from_key(key)
+            .map(Stat::Known)
+            .unwrap_or_else(|| Stat::Other(key.to_owned()))
+    }
+
+    /// The key the API uses for this statistic.
+    pub fn key(&self) -> &str {
+        match self {
+            Stat::Known(kind) => kind.key(),
+            Stat::Other(key) => key,
+        }
+    }
+
+    /// The statistic's kind, if it is known.
+    pub fn kind(&self) -> Option<StatKind> {
+        match self {
+            Stat::Known(kind) => Some(*kind),
+            Stat::Other(_) => None,
+        }
+    }
+}
+
+impl Stat {
+    /// A human-readable name for the statistic. Statistics this crate doesn't
+    /// know about get their key in title case, e.g. `"Fish Released"` for
+    /// `fish_released`.
+    pub fn label(&self) -> Cow<'static, str> {
+        match self {
+            Stat::Known(kind) => Cow::Borrowed(kind.label()),
+            Stat::Other(key) => Cow::Owned(title_case(key)),
+        }
+    }
+
+    /// The category the statistic belongs to. Statistics this crate doesn't
+    /// know about are [`StatCategory::Misc`].
+    pub fn category(&self) -> StatCategory {
+        self.kind()
+            .map_or(StatCategory::Misc, |kind| kind.category())
+    }
+
+    /// The unit the statistic is measured in. Statistics this crate doesn't
+    /// know about are assumed to be a [`StatUnit::Count`].
+    pub fn unit(&self) -> StatUnit {
+        self.kind().map_or(StatUnit::Count, |kind| kind.unit())
+    }
+
+    /// Formats a value of this statistic in its unit.
+    pub fn format_value(&self, value: i64) -> String {
+        self.unit().format(value)
+    }
+}
+
+fn title_case(key: &str) -> String {
+    key.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// The broad group a statistic belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StatCategory {
+    Movement,
+    Combat,
+    Interaction,
+    Redstone,
+    Misc,
+}
+
+impl StatCategory {
+    pub const ALL: [StatCategory; 5] = [
+        StatCategory::Movement,
+        StatCategory::Combat,
+        StatCategory::Interaction,
+        StatCategory::Redstone,
+        StatCategory::Misc,
+    ];
+
+    pub const fn label(&self) -> &'static str {
+        match self {
+            StatCategory::Movement => "Movement",
+            StatCategory::Combat => "Combat",
+            StatCategory::Interaction => "Interaction",
+            StatCategory::Redstone => "Redstone",
+            StatCategory::Misc => "Misc",
+        }
+    }
+}
+
+impl fmt::Display for StatCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// The unit a statistic is recorded in by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatUnit {
+    /// A plain number of times something happened.
+    Count,
+    /// A distance in centimetres.
+    Distance,
+    /// A length of time in ticks.
+    Time,
+    /// An amount of damage in tenths of a health point.
+    Damage,
+}
+
+impl StatUnit {
+    /// Formats a raw value in this unit for display. Distances of at least a
+    /// kilometre are shown in kilometres and shorter ones in blocks, times in
+    /// the largest whole unit from days down to seconds, and damage in
+    /// hearts, e.g. `"1,234 km"`, `"52 days"` or `"17 hearts"`.
+    pub fn format(&self, value: i64) -> String {
+        match self {
+            StatUnit::Count => group_thousands(value),
+            StatUnit::Distance => {
+                let cm = value as f64;
+                if cm.abs() >= CM_PER_KM {
+                    plural((cm / CM_PER_KM).round() as i64, "km", "km")
+                } else {
+                    plural(
+                        (cm / CM_PER_BLOCK).round() as i64,
+                        "block",
+                        "blocks",
+                    )
+                }
+            }
+            StatUnit::Time => {
+                let seconds = value / TICKS_PER_SECOND as i64;
+                let (amount, singular, plural_unit) = match seconds.abs() {
+                    86_400.. => (seconds / 86_400, "day", "days"),
+                    3_600.. => (seconds / 3_600, "hour", "hours"),
+                    60.. => (seconds / 60, "minute", "minutes"),
+                    _ => (seconds, "second", "seconds"),
+                };
+                plural(amount, singular, plural_unit)
+            }
+            StatUnit::Damage => plural(
+                (value as f64 / DAMAGE_PER_HEART).round() as i64,
+                "heart",
+                "hearts",
+            ),
+        }
+    }
+}
+
+fn plural(amount: i64, singular: &str, plural: &str) -> String {
+    let unit = if amount.abs() == 1 { singular } else { plural };
+    format!("{} {unit}", group_thousands(amount))
+}
+
+/// Formats a number with commas between groups of three digits.
+fn group_thousands(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped =
+        String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if value < 0 {
+        grouped.push('-');
+    }
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+impl From<StatKind> for Stat {
+    fn from(kind: StatKind) -> Self {
+        Stat::Known(kind)
+    }
+}
+
+impl fmt::Display for Stat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+/// The statistics of every player on the server added together.
+///
+/// Known statistics are looked up by [`StatKind`] in constant time, and any
+/// others are kept by their API key. It serializes as a flat map of keys to
+/// values, like the API.
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "ts-rs",
+    derive(ts_rs::TS),
+    ts(type = "Record<string, number>")
+)]
+pub struct StatMap {
+    known: [Option<i64>; StatKind::COUNT],
+    other: BTreeMap<String, i64>,
+}
+
+impl StatMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of a known statistic.
+    pub fn get(&self, kind: StatKind) -> Option<i64> {
+        self.known[kind as usize]
+    }
+
+    /// The value of a statistic by its API key, whether it is known or not.
+    pub fn get_key(&self, key: &str) -> Option<i64> {
+        match StatKind::from_key(key) {
+            Some(kind) => self.get(kind),
+            None => self.other.get(key).copied(),
+        }
+    }
+
+    /// Sets the value of a statistic, returning its previous value.
+    pub fn insert(&mut self, stat: impl Into<Stat>, value: i64) -> Option<i64> {
+        match stat.into() {
+            Stat::Known(kind) => self.known[kind as usize].replace(value),
+            Stat::Other(key) => self.other.insert(key, value),
+        }
+    }
+
+    /// Removes a statistic, returning its value.
+    pub fn remove(&mut self, stat: &Stat) -> Option<i64> {
+        match stat {
+            Stat::Known(kind) => self.known[*kind as usize].take(),
+            Stat::Other(key) => self.other.remove(key),
+        }
+    }
+
+    /// The number of statistics in the map.
+    pub fn len(&self) -> usize {
+        self.known.iter().flatten().count() + self.other.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Every statistic and its value. Known statistics come first, followed by
+    /// any others, each ordered by key.
+    pub fn iter(&self) -> impl Iterator<Item = (Stat, i64)> + '_ {
+        self.known()
+            .map(|(kind, value)| (Stat::Known(kind), value))
+            .chain(
+                self.others()
+                    .map(|(key, value)| (Stat::Other(key.to_owned()), value)),
+            )
+    }
+
+    /// Every known statistic and its value, ordered by key.
+    pub fn known(&self) -> impl Iterator<Item = (StatKind, i64)> + '_ {
+        StatKind::ALL
+            .iter()
+            .filter_map(|kind| self.get(*kind).map(|value| (*kind, value)))
+    }
+
+    /// Every statistic this crate doesn't know about, by its API key.
+    pub fn others(&self) -> impl Iterator<Item = (&str, i64)> + '_ {
+        self.other.iter().map(|(key, value)| (key.as_str(), *value))
+    }
+
+    /// A distance statistic in blocks. Returns `None` if the statistic is
+    /// missing or isn't a distance.
+    pub fn blocks(&self, kind: StatKind) -> Option<f64> {
+        self.distance_cm(kind).map(|cm| cm / CM_PER_BLOCK)
+    }
+
+    /// A distance statistic in kilometres. Returns `None` if the statistic is
+    /// missing or isn't a distance.
+    pub fn kilometres(&self, kind: StatKind) -> Option<f64> {
+        self.distance_cm(kind).map(|cm| cm / CM_PER_KM)
+    }
+
+    fn distance_cm(&self, kind: StatKind) -> Option<f64> {
+        kind.is_distance()
+            .then(|| self.get(kind))
+            .flatten()
+            .map(|cm| cm as f64)
+    }
+
+    /// A time statistic as a [`Duration`]. Returns `None` if the statistic is
+    /// missing, negative or isn't a length of time.
+    pub fn duration(&self, kind: StatKind) -> Option<Duration> {
+        let ticks = kind.is_time().then(|| self.get(kind)).flatten()?;
+        let ticks = u64::try_from(ticks).ok()?;
+        Some(Duration::from_millis(ticks * 1000 / TICKS_PER_SECOND))
+    }
+
+    /// A damage statistic in hearts. Returns `None` if the statistic is
+    /// missing or isn't an amount of damage.
+    pub fn hearts(&self, kind: StatKind) -> Option<f64> {
+        kind.is_damage()
+            .then(|| self.get(kind))
+            .flatten()
+            .map(|damage| damage as f64 / DAMAGE_PER_HEART)
+    }
+
+    /// The total time played.
+    pub fn play_time(&self) -> Option<Duration> {
+        self.duration(StatKind::PlayTime)
+    }
+
+    /// The total time spent sneaking.
+    pub fn sneak_time(&self) -> Option<Duration> {
+        self.duration(StatKind::SneakTime)
+    }
+
+    /// The total time since players last died.
+    pub fn time_since_death(&self) -> Option<Duration> {
+        self.duration(StatKind::TimeSinceDeath)
+    }
+
+    /// The total time since players last slept.
+    pub fn time_since_rest(&self) -> Option<Duration> {
+        self.duration(StatKind::TimeSinceRest)
+    }
+}
+
+impl Default for StatMap {
+    fn default() -> Self {
+        Self {
+            known: [None; StatKind::COUNT],
+            other: BTreeMap::new(),
+        }
+    }
+}
+
+/// Formats as a map of API keys to values.
+impl fmt::Debug for StatMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.known().map(|(kind, value)| (kind.key(), value)))
+            .entries(self.others())
+            .finish()
+    }
+}
+
+impl<S: Into<Stat>> FromIterator<(S, i64)> for StatMap {
+    fn from_iter<I: IntoIterator<Item = (S, i64)>>(iter: I) -> Self {
+        let mut map = StatMap::new();
+        for (stat, value) in iter {
+            map.insert(stat, value);
+        }
+        map
+    }
+}
+
+impl From<BTreeMap<String, i64>> for StatMap {
+    fn from(map: BTreeMap<String, i64>) -> Self {
+        map.into_iter()
+            .map(|(key, value)| (Stat::from_key(&key), value))
+            .collect()
+    }
+}
+
+impl From<StatMap> for BTreeMap<String, i64> {
+    fn from(stat_map: StatMap) -> Self {
+        stat_map
+            .iter()
+            .map(|(stat, value)| (stat.key().to_owned(), value))
+            .collect()
+    }
+}
+
+/// Serializes as a flat map of API keys to values, like the API.
+impl Serialize for StatMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sorted: BTreeMap<&str, i64> = self
+            .known()
+            .map(|(kind, value)| (kind.key(), value))
+            .chain(self.others())
+            .collect();
+        sorted.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StatMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        BTreeMap::<String, i64>::deserialize(deserializer).map(StatMap::from)
+    }
+}
+
+/// Serializes as a flat map of stat names to values, like the API.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for StatMap {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "StatMap".into()
+    }
+
+    fn json_schema(
+        generator: &mut schemars::SchemaGenerator,
+    ) -> schemars::Schema {
+        <BTreeMap<String, i64>>::json_schema(generator)
+    }
+}
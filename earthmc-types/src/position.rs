@@ -0,0 +1,243 @@
+//! # Position
+//!
+//! Defines the [`BlockPos`], [`BlockPosXZ`] and [`ChunkPos`] structs.
+//!
+//! The API sends these as bare integer arrays (`[x, y, z]` or `[x, z]`),
+//! and they serialize back to the same format. A chunk, which Towny calls a
+//! town block, is [`CHUNK_SIZE`] blocks wide.
+use serde::{Deserialize, Serialize};
+
+use crate::location::PositionXZ;
+
+/// The width of a chunk in blocks.
+pub const CHUNK_SIZE: i32 = 16;
+
+/// The position of a block.
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+)]
+#[cfg_attr(
+    feature = "ts-rs",
+    derive(ts_rs::TS),
+    ts(type = "[number, number, number]")
+)]
+#[serde(from = "[i32; 3]", into = "[i32; 3]")]
+pub struct BlockPos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// The position of a block column, ignoring height.
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(type = "[number, number]"))]
+#[serde(from = "[i32; 2]", into = "[i32; 2]")]
+pub struct BlockPosXZ {
+    pub x: i32,
+    pub z: i32,
+}
+
+/// The position of a chunk, in chunk coordinates.
+#[derive(
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Default,
+)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(type = "[number, number]"))]
+#[serde(from = "[i32; 2]", into = "[i32; 2]")]
+pub struct ChunkPos {
+    pub x: i32,
+    pub z: i32,
+}
+
+impl BlockPos {
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The block column this block is in.
+    pub const fn xz(&self) -> BlockPosXZ {
+        BlockPosXZ::new(self.x, self.z)
+    }
+
+    /// The chunk this block is in.
+    pub const fn chunk(&self) -> ChunkPos {
+        self.xz().chunk()
+    }
+}
+
+impl BlockPosXZ {
+    pub const fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
+    /// The block at height `y` in this column.
+    pub const fn with_y(&self, y: i32) -> BlockPos {
+        BlockPos::new(self.x, y, self.z)
+    }
+
+    /// The chunk this column is in.
+    pub const fn chunk(&self) -> ChunkPos {
+        ChunkPos::new(
+            self.x.div_euclid(CHUNK_SIZE),
+            self.z.div_euclid(CHUNK_SIZE),
+        )
+    }
+}
+
+impl ChunkPos {
+    pub const fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
+    /// The north-west (lowest x and z) block column of this chunk.
+    pub const fn min_block(&self) -> BlockPosXZ {
+        BlockPosXZ::new(self.x * CHUNK_SIZE, self.z * CHUNK_SIZE)
+    }
+
+    /// The south-east (highest x and z) block column of this chunk.
+    pub const fn max_block(&self) -> BlockPosXZ {
+        BlockPosXZ::new(
+            self.x * CHUNK_SIZE + CHUNK_SIZE - 1,
+            self.z * CHUNK_SIZE + CHUNK_SIZE - 1,
+        )
+    }
+
+    /// The block column at the centre of this chunk. Chunks are an even
+    /// number of blocks wide, so this rounds towards [`Self::max_block`].
+    pub const fn center_block(&self) -> BlockPosXZ {
+        BlockPosXZ::new(
+            self.x * CHUNK_SIZE + CHUNK_SIZE / 2,
+            self.z * CHUNK_SIZE + CHUNK_SIZE / 2,
+        )
+    }
+
+    /// Whether `block` is inside this chunk.
+    pub const fn contains(&self, block: BlockPosXZ) -> bool {
+        let chunk = block.chunk();
+        chunk.x == self.x && chunk.z == self.z
+    }
+}
+
+impl From<[i32; 3]> for BlockPos {
+    fn from([x, y, z]: [i32; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<BlockPos> for [i32; 3] {
+    fn from(pos: BlockPos) -> Self {
+        [pos.x, pos.y, pos.z]
+    }
+}
+
+impl From<[i32; 2]> for BlockPosXZ {
+    fn from([x, z]: [i32; 2]) -> Self {
+        Self::new(x, z)
+    }
+}
+
+impl From<BlockPosXZ> for [i32; 2] {
+    fn from(pos: BlockPosXZ) -> Self {
+        [pos.x, pos.z]
+    }
+}
+
+impl From<[i32; 2]> for ChunkPos {
+    fn from([x, z]: [i32; 2]) -> Self {
+        Self::new(x, z)
+    }
+}
+
+impl From<ChunkPos> for [i32; 2] {
+    fn from(pos: ChunkPos) -> Self {
+        [pos.x, pos.z]
+    }
+}
+
+impl From<BlockPos> for BlockPosXZ {
+    fn from(pos: BlockPos) -> Self {
+        pos.xz()
+    }
+}
+
+impl From<BlockPos> for ChunkPos {
+    fn from(pos: BlockPos) -> Self {
+        pos.chunk()
+    }
+}
+
+impl From<BlockPosXZ> for ChunkPos {
+    fn from(pos: BlockPosXZ) -> Self {
+        pos.chunk()
+    }
+}
+
+/// Rounds down to the block column containing the position.
+impl From<PositionXZ> for BlockPosXZ {
+    fn from(pos: PositionXZ) -> Self {
+        Self::new(pos.x.floor() as i32, pos.z.floor() as i32)
+    }
+}
+
+impl From<PositionXZ> for ChunkPos {
+    fn from(pos: PositionXZ) -> Self {
+        BlockPosXZ::from(pos).chunk()
+    }
+}
+
+#[cfg(feature = "schemars")]
+macro_rules! array_schema {
+    ($ty:ty, $array:ty, $description:literal) => {
+        impl schemars::JsonSchema for $ty {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                stringify!($ty).into()
+            }
+
+            fn json_schema(
+                generator: &mut schemars::SchemaGenerator,
+            ) -> schemars::Schema {
+                let mut schema = <$array>::json_schema(generator);
+                schema.insert("description".into(), $description.into());
+                schema
+            }
+        }
+    };
+}
+
+#[cfg(feature = "schemars")]
+array_schema!(BlockPos, [i32; 3], "Block coordinates as `[x, y, z]`.");
+#[cfg(feature = "schemars")]
+array_schema!(BlockPosXZ, [i32; 2], "Block coordinates as `[x, z]`.");
+#[cfg(feature = "schemars")]
+array_schema!(ChunkPos, [i32; 2], "Chunk coordinates as `[x, z]`.");
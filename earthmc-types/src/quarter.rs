@@ -0,0 +1,211 @@
+//! # Quarter
+//!
+//! Defines the [`Quarter`] struct.
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    colour::Rgba,
+    geometry::BlockRect,
+    id::{PlayerId, QuarterId},
+    named_id::{NamedId, NamedIdOpt},
+    player::Player,
+    position::{BlockPos, ChunkPos},
+    timestamp::Timestamp,
+    town::Town,
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct Quarter {
+    /// The Quarter's name.
+    pub name: String,
+    /// The Quarter's ID.
+    pub uuid: QuarterId,
+    /// The Quarter's kind, or as it is referred to in the API, its `type`.
+    #[serde(rename = "type")]
+    pub kind: QuarterKind,
+    /// The ID of the creator of the Quarter.
+    pub creator: PlayerId,
+    /// The Quarter's owner.
+    pub owner: NamedIdOpt<Player>,
+    /// The town this Quarter is inside.
+    pub town: NamedId<Town>,
+    pub timestamps: QuarterTimestamps,
+    pub status: QuarterStatus,
+    pub stats: QuarterStats,
+    /// The Quarter's colour.
+    pub colour: Rgba,
+    /// All of the trusted players in this Quarter.
+    pub trusted: Vec<NamedId<Player>>,
+    /// Every cuboid in this Quarter.
+    pub cuboids: Vec<QuarterCuboid>,
+}
+
+impl Quarter {
+    /// The number of blocks in every cuboid together, which should match
+    /// [`QuarterStats::volume`].
+    pub fn volume(&self) -> i64 {
+        self.cuboids.iter().map(QuarterCuboid::volume).sum()
+    }
+
+    /// Whether any cuboid contains `block`.
+    pub fn contains(&self, block: BlockPos) -> bool {
+        self.cuboids.iter().any(|cuboid| cuboid.contains(block))
+    }
+
+    /// The chunks any cuboid overlaps, sorted and without duplicates.
+    pub fn chunks(&self) -> Vec<ChunkPos> {
+        let mut chunks: Vec<ChunkPos> = self
+            .cuboids
+            .iter()
+            .flat_map(QuarterCuboid::chunks)
+            .collect();
+        chunks.sort_unstable();
+        chunks.dedup();
+        chunks
+    }
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum QuarterKind {
+    /// The default kind of Quarter.
+    #[default]
+    Apartment,
+    /// Allows bed usage.
+    Inn,
+    /// Allows vehicle placing and usage.
+    Station,
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct QuarterTimestamps {
+    /// When the Quarter was created.
+    pub registered: Timestamp,
+    /// When the Quarter was claimed.
+    pub claimed_at: Option<Timestamp>,
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct QuarterStatus {
+    /// If the Quarter is an embassy.
+    pub is_embassy: bool,
+    /// If the Quarter is for sale.
+    pub is_for_sale: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct QuarterStats {
+    /// The Quarter's sale price.
+    pub price: Option<i32>,
+    /// The total number of blocks within the Quarter's bounds.
+    pub volume: i32,
+    /// The total amount of cuboids this Quarter is made of.
+    pub num_cuboids: u16,
+    /// The size of the particles displayed in-game.
+    pub particle_size: Option<f32>,
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[serde(rename_all = "camelCase")]
+pub struct QuarterCuboid {
+    pub corner_one: BlockPos,
+    pub corner_two: BlockPos,
+}
+
+impl QuarterCuboid {
+    pub const fn new(corner_one: BlockPos, corner_two: BlockPos) -> Self {
+        Self {
+            corner_one,
+            corner_two,
+        }
+    }
+
+    /// The corner with the lowest coordinates.
+    pub fn min(&self) -> BlockPos {
+        let (a, b) = (self.corner_one, self.corner_two);
+        BlockPos::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z))
+    }
+
+    /// The corner with the highest coordinates.
+    pub fn max(&self) -> BlockPos {
+        let (a, b) = (self.corner_one, self.corner_two);
+        BlockPos::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z))
+    }
+
+    /// The same cuboid with [`QuarterCuboid::min`] as `corner_one` and
+    /// [`QuarterCuboid::max`] as `corner_two`.
+    pub fn normalized(&self) -> Self {
+        Self::new(self.min(), self.max())
+    }
+
+    /// The number of blocks inside, counting both corners.
+    pub fn volume(&self) -> i64 {
+        let (min, max) = (self.min(), self.max());
+        let side = |a: i32, b: i32| b as i64 - a as i64 + 1;
+        side(min.x, max.x) * side(min.y, max.y) * side(min.z, max.z)
+    }
+
+    pub fn contains(&self, block: BlockPos) -> bool {
+        let (min, max) = (self.min(), self.max());
+        (min.x..=max.x).contains(&block.x)
+            && (min.y..=max.y).contains(&block.y)
+            && (min.z..=max.z).contains(&block.z)
+    }
+
+    /// Whether the cuboids share at least one block.
+    pub fn intersects(&self, other: &QuarterCuboid) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The blocks both cuboids contain, if any.
+    pub fn intersection(&self, other: &QuarterCuboid) -> Option<Self> {
+        let (a_min, a_max) = (self.min(), self.max());
+        let (b_min, b_max) = (other.min(), other.max());
+        let min = BlockPos::new(
+            a_min.x.max(b_min.x),
+            a_min.y.max(b_min.y),
+            a_min.z.max(b_min.z),
+        );
+        let max = BlockPos::new(
+            a_max.x.min(b_max.x),
+            a_max.y.min(b_max.y),
+            a_max.z.min(b_max.z),
+        );
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z)
+            .then_some(Self::new(min, max))
+    }
+
+    /// The area the cuboid covers from above.
+    pub fn footprint(&self) -> BlockRect {
+        BlockRect::new(self.corner_one.xz(), self.corner_two.xz())
+    }
+
+    /// The chunks the cuboid overlaps.
+    pub fn chunks(&self) -> impl Iterator<Item = ChunkPos> + use<> {
+        self.footprint().chunks()
+    }
+}
@@ -0,0 +1,129 @@
+//! # Quarter Check
+//!
+//! Defines [`check_quarters`], which looks for problems with a town's
+//! quarters: quarters that overlap, cuboids outside the town's claims, and
+//! reported volumes that don't match the cuboids.
+//!
+//! ```
+//! use earthmc_types::{
+//!     position::{BlockPos, ChunkPos},
+//!     quarter::{Quarter, QuarterCuboid},
+//!     quarter_check::{QuarterIssue, check_quarters},
+//!     town::Town,
+//! };
+//!
+//! let mut town = Town::default();
+//! town.coordinates.town_blocks = vec![ChunkPos::new(0, 0)];
+//!
+//! let mut quarter = Quarter::default();
+//! quarter.cuboids = vec![QuarterCuboid::new(
+//!     BlockPos::new(0, 64, 0),
+//!     BlockPos::new(3, 67, 3),
+//! )];
+//! quarter.stats.volume = 64;
+//!
+//! assert!(check_quarters(&town, [&quarter]).is_empty());
+//! ```
+use std::collections::BTreeSet;
+
+use crate::{
+    named_id::NamedId,
+    position::ChunkPos,
+    quarter::{Quarter, QuarterCuboid},
+    town::Town,
+};
+
+/// A problem found by [`check_quarters`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuarterIssue {
+    /// Two quarters share blocks.
+    Overlap {
+        quarters: [NamedId<Quarter>; 2],
+        /// The blocks each pair of their cuboids share.
+        overlaps: Vec<QuarterCuboid>,
+    },
+    /// A quarter reaches into chunks the town hasn't claimed.
+    OutsideTown {
+        quarter: NamedId<Quarter>,
+        /// The unclaimed chunks, sorted.
+        chunks: Vec<ChunkPos>,
+    },
+    /// A quarter's reported volume doesn't match its cuboids.
+    VolumeMismatch {
+        quarter: NamedId<Quarter>,
+        /// [`QuarterStats::volume`](crate::quarter::QuarterStats::volume).
+        reported: i32,
+        /// [`Quarter::volume`].
+        computed: i64,
+    },
+}
+
+/// Checks `quarters` against `town` and each other. Quarters that belong to
+/// another town are skipped.
+///
+/// Issues are ordered by kind, with overlaps first, then in the order the
+/// quarters were given.
+pub fn check_quarters<'a>(
+    town: &Town,
+    quarters: impl IntoIterator<Item = &'a Quarter>,
+) -> Vec<QuarterIssue> {
+    let quarters: Vec<&Quarter> = quarters
+        .into_iter()
+        .filter(|quarter| quarter.town.uuid == town.uuid)
+        .collect();
+    let claimed: BTreeSet<ChunkPos> =
+        town.coordinates.town_blocks.iter().copied().collect();
+    let mut issues = Vec::new();
+
+    for (i, a) in quarters.iter().enumerate() {
+        for b in &quarters[i + 1..] {
+            let overlaps: Vec<QuarterCuboid> = a
+                .cuboids
+                .iter()
+                .flat_map(|x| {
+                    b.cuboids.iter().filter_map(|y| x.intersection(y))
+                })
+                .collect();
+            if !overlaps.is_empty() {
+                issues.push(QuarterIssue::Overlap {
+                    quarters: [named_quarter(a), named_quarter(b)],
+                    overlaps,
+                });
+            }
+        }
+    }
+
+    for quarter in &quarters {
+        let chunks: Vec<ChunkPos> = quarter
+            .chunks()
+            .into_iter()
+            .filter(|chunk| !claimed.contains(chunk))
+            .collect();
+        if !chunks.is_empty() {
+            issues.push(QuarterIssue::OutsideTown {
+                quarter: named_quarter(quarter),
+                chunks,
+            });
+        }
+    }
+
+    for quarter in &quarters {
+        let computed = quarter.volume();
+        if computed != quarter.stats.volume as i64 {
+            issues.push(QuarterIssue::VolumeMismatch {
+                quarter: named_quarter(quarter),
+                reported: quarter.stats.volume,
+                computed,
+            });
+        }
+    }
+
+    issues
+}
+
+fn named_quarter(quarter: &Quarter) -> NamedId<Quarter> {
+    NamedId {
+        name: quarter.name.clone(),
+        uuid: quarter.uuid,
+    }
+}
@@ -3,7 +3,9 @@
 //! Defines the [`Server`] struct.
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct Server {
     /// The server's current version as a string
@@ -20,16 +22,25 @@ pub struct Server {
     pub vote_party: VoteParty,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct ServerTimestamps {
     /// Time the new day occurs at.
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
     pub new_day_time: i64,
     /// The time of day, in seconds, in the server's current timezone.
     pub server_time_of_day: i32,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct ServerStatus {
     /// If the server is currently raining.
@@ -38,12 +49,18 @@ pub struct ServerStatus {
     pub is_thundering: bool,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct ServerStats {
     /// The amount of ticks that have passed within the current day.
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
     pub time: i64,
     /// The amount of ticks that have ever passed.
+    #[cfg_attr(feature = "ts-rs", ts(type = "number"))]
     pub full_time: i64,
     /// The total amount of players that can connect to the server.
     pub max_players: i32,
@@ -67,7 +84,11 @@ pub struct ServerStats {
     pub num_cuboids: i32,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct VoteParty {
     /// The total votes required to trigger a vote party.
@@ -76,7 +97,9 @@ pub struct VoteParty {
     pub num_remaining: i32,
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum MoonPhase {
     FirstQuarter,
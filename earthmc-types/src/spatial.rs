@@ -0,0 +1,159 @@
+//! # Spatial
+//!
+//! Defines [`TownIndex`], an in-memory index of town claims that answers
+//! location lookups without calling the API.
+//!
+//! ```
+//! use earthmc_types::{
+//!     geometry::BlockRect,
+//!     location::PositionXZ,
+//!     position::{BlockPosXZ, ChunkPos},
+//!     spatial::TownIndex,
+//!     town::Town,
+//! };
+//!
+//! let mut town = Town::default();
+//! town.name = "Spawn".to_string();
+//! town.coordinates.town_blocks =
+//!     vec![ChunkPos::new(0, 0), ChunkPos::new(1, 0)];
+//! let index = TownIndex::new([&town]);
+//!
+//! let owner = index.town_at(BlockPosXZ::new(20, 5)).unwrap();
+//! assert_eq!(owner.town.name, "Spawn");
+//! assert!(index.location_info(PositionXZ { x: -0.5, z: 0.0 }).is_wilderness);
+//!
+//! let rect =
+//!     BlockRect::new(BlockPosXZ::new(-100, -100), BlockPosXZ::new(0, 0));
+//! assert_eq!(index.towns_in(rect).len(), 1);
+//! ```
+use std::collections::HashMap;
+
+use crate::{
+    geometry::BlockRect,
+    location::{LocationInfo, PositionXZ},
+    named_id::{NamedId, NamedIdOpt},
+    nation::Nation,
+    position::{BlockPosXZ, ChunkPos},
+    town::Town,
+};
+
+/// A town in a [`TownIndex`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndexedTown {
+    pub town: NamedId<Town>,
+    pub nation: NamedIdOpt<Nation>,
+}
+
+/// Maps every claimed chunk to the town that owns it.
+///
+/// Lookups are a single hash map access, so they are cheap enough to run on
+/// every mouse move. The index is a snapshot; rebuild it to pick up new
+/// claims.
+#[derive(Debug, Clone, Default)]
+pub struct TownIndex {
+    towns: Vec<IndexedTown>,
+    chunks: HashMap<ChunkPos, usize>,
+}
+
+impl TownIndex {
+    /// Indexes the claims of `towns`. If two towns claim the same chunk,
+    /// which the API shouldn't return, the first one keeps it.
+    pub fn new<'a>(towns: impl IntoIterator<Item = &'a Town>) -> Self {
+        let mut index = Self::default();
+        for town in towns {
+            index.insert(town);
+        }
+        index
+    }
+
+    /// Adds the claims of `town`, keeping chunks already claimed by another
+    /// town.
+    pub fn insert(&mut self, town: &Town) {
+        let i = self.towns.len();
+        self.towns.push(IndexedTown {
+            town: NamedId {
+                name: town.name.clone(),
+                uuid: town.uuid,
+            },
+            nation: town.nation.clone(),
+        });
+        for &chunk in &town.coordinates.town_blocks {
+            self.chunks.entry(chunk).or_insert(i);
+        }
+    }
+
+    /// Every indexed town, in the order they were added.
+    pub fn towns(&self) -> &[IndexedTown] {
+        &self.towns
+    }
+
+    /// The number of claimed chunks.
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// The town claiming `chunk`, if any.
+    pub fn town_at_chunk(&self, chunk: ChunkPos) -> Option<&IndexedTown> {
+        self.chunks.get(&chunk).map(|&i| &self.towns[i])
+    }
+
+    /// The town claiming the chunk `block` is in, if any.
+    pub fn town_at(&self, block: BlockPosXZ) -> Option<&IndexedTown> {
+        self.town_at_chunk(block.chunk())
+    }
+
+    /// Answers like `Client::locations` in the `earthmc` crate would for
+    /// `location`, without the request.
+    pub fn location_info(&self, location: PositionXZ) -> LocationInfo {
+        let owner = self.town_at_chunk(location.into());
+        LocationInfo {
+            location,
+            is_wilderness: owner.is_none(),
+            town: owner
+                .map(|owner| NamedIdOpt {
+                    name: Some(owner.town.name.clone()),
+                    uuid: Some(owner.town.uuid),
+                })
+                .unwrap_or_default(),
+            nation: owner.map(|owner| owner.nation.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// The towns with at least one chunk overlapping `rect`, in the order
+    /// they were added.
+    pub fn towns_in(&self, rect: BlockRect) -> Vec<&IndexedTown> {
+        let (min, max) = (rect.min.chunk(), rect.max.chunk());
+        let width = (max.x as i64 - min.x as i64) as u64 + 1;
+        let depth = (max.z as i64 - min.z as i64) as u64 + 1;
+
+        let mut found = vec![false; self.towns.len()];
+        if width.saturating_mul(depth) <= self.chunks.len() as u64 {
+            for chunk in rect.chunks() {
+                if let Some(&i) = self.chunks.get(&chunk) {
+                    found[i] = true;
+                }
+            }
+        } else {
+            // the rectangle is bigger than the claims, so scan the claims
+            for (chunk, &i) in &self.chunks {
+                let inside = min.x <= chunk.x
+                    && chunk.x <= max.x
+                    && min.z <= chunk.z
+                    && chunk.z <= max.z;
+                found[i] |= inside;
+            }
+        }
+
+        self.towns
+            .iter()
+            .zip(found)
+            .filter_map(|(town, found)| found.then_some(town))
+            .collect()
+    }
+}
+
+impl<'a> FromIterator<&'a Town> for TownIndex {
+    fn from_iter<I: IntoIterator<Item = &'a Town>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
@@ -0,0 +1,263 @@
+//! # Stat History
+//!
+//! Defines [`StatDiff`], the change in [`StatMap`] between two readings, and
+//! [`StatHistory`], a ring buffer of readings.
+//!
+//! A single [`StatMap`] is a running total since the server started tracking,
+//! so on its own it says little about recent activity. Comparing two
+//! [`StatSample`]s taken at known times gives per-stat deltas and rates:
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use earthmc_types::{
+//!     player_stats::{StatKind, StatMap},
+//!     stat_history::{StatHistory, StatSample},
+//!     timestamp::Timestamp,
+//! };
+//!
+//! let hour = 60 * 60 * 1000;
+//! let mut history = StatHistory::new(24);
+//! for (i, walked_cm) in [0, 150_000_000, 400_000_000].into_iter().enumerate() {
+//!     let stats = StatMap::from_iter([(StatKind::WalkOneCm, walked_cm)]);
+//!     let taken_at = Timestamp::from_millis(i as i64 * hour);
+//!     history.push(StatSample::new(taken_at, stats));
+//! }
+//!
+//! // Blocks walked server-wide in the last hour.
+//! let diff = history.diff_over(Duration::from_secs(60 * 60)).unwrap();
+//! let walked_cm = diff.delta(StatKind::WalkOneCm).unwrap();
+//! assert_eq!(walked_cm, 250_000_000);
+//! assert_eq!(StatKind::WalkOneCm.format_value(walked_cm), "2,500 km");
+//! ```
+use std::{collections::VecDeque, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    player_stats::{Stat, StatKind, StatMap},
+    timestamp::Timestamp,
+};
+
+/// A [`StatMap`] and the time it was read.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct StatSample {
+    pub taken_at: Timestamp,
+    pub stats: StatMap,
+}
+
+impl StatSample {
+    pub fn new(taken_at: Timestamp, stats: StatMap) -> Self {
+        Self { taken_at, stats }
+    }
+
+    /// A sample of `stats` taken now.
+    pub fn now(stats: StatMap) -> Self {
+        Self::new(Timestamp::now(), stats)
+    }
+}
+
+/// The change in one statistic between two readings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatChange {
+    pub stat: Stat,
+    pub before: i64,
+    pub after: i64,
+}
+
+impl StatChange {
+    /// How much the statistic changed by.
+    pub fn delta(&self) -> i64 {
+        self.after - self.before
+    }
+
+    /// The change as a fraction of the earlier value, e.g. `0.5` when the
+    /// statistic grew by half. Returns `None` if the earlier value was zero.
+    pub fn relative(&self) -> Option<f64> {
+        (self.before != 0)
+            .then(|| self.delta() as f64 / self.before.unsigned_abs() as f64)
+    }
+}
+
+/// The change in every statistic between two [`StatSample`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatDiff {
+    earlier: StatSample,
+    later: StatSample,
+}
+
+impl StatDiff {
+    /// Compares an earlier reading with a later one.
+    pub fn between(earlier: &StatSample, later: &StatSample) -> Self {
+        Self {
+            earlier: earlier.clone(),
+            later: later.clone(),
+        }
+    }
+
+    /// When the earlier reading was taken.
+    pub fn from(&self) -> Timestamp {
+        self.earlier.taken_at
+    }
+
+    /// When the later reading was taken.
+    pub fn to(&self) -> Timestamp {
+        self.later.taken_at
+    }
+
+    /// The time between the two readings, or zero if they are out of order.
+    pub fn elapsed(&self) -> Duration {
+        self.to().duration_since(self.from()).unwrap_or_default()
+    }
+
+    /// How much a known statistic changed by. Returns `None` if either
+    /// reading is missing it.
+    pub fn delta(&self, kind: StatKind) -> Option<i64> {
+        Some(self.later.stats.get(kind)? - self.earlier.stats.get(kind)?)
+    }
+
+    /// How much a known statistic changed by per hour. Returns `None` if
+    /// either reading is missing it or no time passed between them.
+    pub fn rate_per_hour(&self, kind: StatKind) -> Option<f64> {
+        self.per_hour(self.delta(kind)?)
+    }
+
+    fn per_hour(&self, delta: i64) -> Option<f64> {
+        let hours = self.elapsed().as_secs_f64() / 3600.0;
+        (hours > 0.0).then(|| delta as f64 / hours)
+    }
+
+    /// Every statistic present in both readings and how it changed.
+    pub fn changes(&self) -> impl Iterator<Item = StatChange> + '_ {
+        self.later.stats.iter().filter_map(|(stat, after)| {
+            let before = self.earlier.stats.get_key(stat.key())?;
+            Some(StatChange {
+                stat,
+                before,
+                after,
+            })
+        })
+    }
+
+    /// The delta of every statistic present in both readings.
+    pub fn deltas(&self) -> StatMap {
+        self.changes()
+            .map(|change| {
+                let delta = change.delta();
+                (change.stat, delta)
+            })
+            .collect()
+    }
+
+    /// The rate per hour of every statistic present in both readings. Empty if
+    /// no time passed between them.
+    pub fn rates_per_hour(&self) -> impl Iterator<Item = (Stat, f64)> + '_ {
+        self.changes().filter_map(|change| {
+            let rate = self.per_hour(change.delta())?;
+            Some((change.stat, rate))
+        })
+    }
+
+    /// The `n` statistics that grew the most relative to their earlier value.
+    ///
+    /// Statistics are measured in different units, so they are ranked by
+    /// [`StatChange::relative`] rather than by their raw delta. Statistics that
+    /// were zero in the earlier reading, or didn't grow, are left out.
+    pub fn top_movers(&self, n: usize) -> Vec<StatChange> {
+        let mut movers: Vec<(f64, StatChange)> = self
+            .changes()
+            .filter_map(|change| {
+                let relative = change.relative()?;
+                (relative > 0.0).then_some((relative, change))
+            })
+            .collect();
+        movers.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        movers
+            .into_iter()
+            .take(n)
+            .map(|(_, change)| change)
+            .collect()
+    }
+}
+
+/// A ring buffer of [`StatSample`]s, oldest first.
+///
+/// Once it holds `capacity` samples, pushing another drops the oldest.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatHistory {
+    capacity: usize,
+    samples: VecDeque<StatSample>,
+}
+
+impl StatHistory {
+    /// Creates an empty history that keeps up to `capacity` samples. A
+    /// capacity of zero is treated as one.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Adds a sample, dropping the oldest if the history is full.
+    pub fn push(&mut self, sample: StatSample) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Every sample, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &StatSample> {
+        self.samples.iter()
+    }
+
+    pub fn oldest(&self) -> Option<&StatSample> {
+        self.samples.front()
+    }
+
+    pub fn latest(&self) -> Option<&StatSample> {
+        self.samples.back()
+    }
+
+    /// The change between the oldest and latest samples. Returns `None` if
+    /// there are fewer than two samples.
+    pub fn diff(&self) -> Option<StatDiff> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+        Some(StatDiff::between(self.oldest()?, self.latest()?))
+    }
+
+    /// The change over roughly the last `window`, from the oldest sample
+    /// taken within the window to the latest one. If the history doesn't
+    /// reach back that far, the diff covers less time; check
+    /// [`StatDiff::elapsed`]. Returns `None` if no earlier sample falls
+    /// within the window.
+    pub fn diff_over(&self, window: Duration) -> Option<StatDiff> {
+        let latest = self.latest()?;
+        let window = i64::try_from(window.as_millis()).unwrap_or(i64::MAX);
+        let start = latest.taken_at.as_millis().saturating_sub(window);
+        let earlier = self
+            .samples
+            .iter()
+            .find(|sample| sample.taken_at.as_millis() >= start)?;
+        (earlier != latest).then(|| StatDiff::between(earlier, latest))
+    }
+}
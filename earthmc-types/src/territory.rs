@@ -0,0 +1,294 @@
+//! # Territory
+//!
+//! Defines [`TerritoryMap`], which combines towns' claims into the territory
+//! of their nations and finds the borders between nations.
+//!
+//! Two nations share a border where a chunk of one is next to a chunk of the
+//! other. Chunks that only touch at a corner don't count, as in
+//! [`geometry`](crate::geometry).
+//!
+//! ```
+//! use earthmc_types::{
+//!     position::ChunkPos, territory::TerritoryMap, town::Town,
+//! };
+//!
+//! let town = |id: u8, x: i32| {
+//!     let uuid = format!("00000000-0000-0000-0000-{id:012}");
+//!     let mut town = Town::default();
+//!     town.name = format!("Town {id}");
+//!     town.uuid = uuid.parse().unwrap();
+//!     town.nation.name = Some(format!("Nation {id}"));
+//!     town.nation.uuid = Some(uuid.parse().unwrap());
+//!     town.coordinates.town_blocks =
+//!         vec![ChunkPos::new(x, 0), ChunkPos::new(x, 1)];
+//!     town
+//! };
+//! // two towns of different nations, side by side
+//! let towns = [town(1, 0), town(2, 1)];
+//! let map = TerritoryMap::new(&towns);
+//!
+//! assert_eq!(map.borders().len(), 1);
+//! assert_eq!(map.borders()[0].length(), 2 * 16);
+//! assert_eq!(map.frontier_towns().count(), 2);
+//! ```
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, HashMap},
+};
+
+use crate::{
+    geometry::ClaimShape,
+    id::{NationId, TownId},
+    named_id::NamedId,
+    nation::Nation,
+    position::{CHUNK_SIZE, ChunkPos},
+    town::Town,
+};
+
+/// The combined claims of a nation's towns.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NationTerritory {
+    pub nation: NamedId<Nation>,
+    /// The member towns, in the order they were given.
+    pub towns: Vec<NamedId<Town>>,
+    pub shape: ClaimShape,
+}
+
+impl NationTerritory {
+    /// The total area in blocks.
+    pub fn area(&self) -> i64 {
+        self.shape.area()
+    }
+
+    pub fn num_chunks(&self) -> usize {
+        self.shape.num_chunks()
+    }
+}
+
+/// A border shared by two nations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Border {
+    /// The two nations, ordered by ID.
+    pub nations: [NamedId<Nation>; 2],
+    /// The number of chunk edges the nations share.
+    pub edges: usize,
+}
+
+impl Border {
+    /// The length of the border in blocks.
+    pub fn length(&self) -> i64 {
+        self.edges as i64 * CHUNK_SIZE as i64
+    }
+
+    pub fn involves(&self, nation: NationId) -> bool {
+        self.nations.iter().any(|n| n.uuid == nation)
+    }
+
+    /// The nation on the other side from `nation`, if it's on this border.
+    pub fn other(&self, nation: NationId) -> Option<&NamedId<Nation>> {
+        match &self.nations {
+            [a, b] if a.uuid == nation => Some(b),
+            [a, b] if b.uuid == nation => Some(a),
+            _ => None,
+        }
+    }
+
+    /// How `nation` regards the nation across the border, going by its
+    /// allies and enemies. Returns `None` if `nation` isn't on this border.
+    pub fn diplomacy(&self, nation: &Nation) -> Option<Diplomacy> {
+        let other = self.other(nation.uuid)?.uuid;
+        Some(if nation.allies.iter().any(|n| n.uuid == other) {
+            Diplomacy::Allied
+        } else if nation.enemies.iter().any(|n| n.uuid == other) {
+            Diplomacy::Enemy
+        } else {
+            Diplomacy::Neutral
+        })
+    }
+}
+
+/// How one nation regards another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diplomacy {
+    Allied,
+    Enemy,
+    Neutral,
+}
+
+/// A town with a chunk next to another nation's territory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrontierTown {
+    pub town: NamedId<Town>,
+    pub nation: NamedId<Nation>,
+    /// The nations the town borders, ordered by ID.
+    pub neighbours: Vec<NamedId<Nation>>,
+}
+
+/// Nation territories and the borders between them.
+#[derive(Debug, Clone, Default)]
+pub struct TerritoryMap {
+    nations: Vec<NationTerritory>,
+    borders: Vec<Border>,
+    frontier: Vec<FrontierTown>,
+}
+
+impl TerritoryMap {
+    /// Combines the claims of `towns` by nation. Towns without a nation are
+    /// left out, and don't form borders with anyone.
+    pub fn new<'a>(towns: impl IntoIterator<Item = &'a Town>) -> Self {
+        let mut members: BTreeMap<NationId, (NamedId<Nation>, Vec<&Town>)> =
+            BTreeMap::new();
+        for town in towns {
+            let (Some(name), Some(uuid)) =
+                (&town.nation.name, town.nation.uuid)
+            else {
+                continue;
+            };
+            members
+                .entry(uuid)
+                .or_insert_with(|| {
+                    (
+                        NamedId {
+                            name: name.clone(),
+                            uuid,
+                        },
+                        Vec::new(),
+                    )
+                })
+                .1
+                .push(town);
+        }
+
+        // the town and nation claiming each chunk
+        let mut owners: HashMap<ChunkPos, (TownId, NationId)> = HashMap::new();
+        for (&nation, (_, towns)) in &members {
+            for town in towns {
+                for &chunk in &town.coordinates.town_blocks {
+                    owners.entry(chunk).or_insert((town.uuid, nation));
+                }
+            }
+        }
+
+        let mut edges: BTreeMap<(NationId, NationId), usize> = BTreeMap::new();
+        let mut frontier: BTreeMap<TownId, BTreeSet<NationId>> =
+            BTreeMap::new();
+        for (chunk, &(town, nation)) in &owners {
+            // only look east and south, so each edge is counted once
+            let east = ChunkPos::new(chunk.x + 1, chunk.z);
+            let south = ChunkPos::new(chunk.x, chunk.z + 1);
+            for neighbour in [east, south] {
+                let Some(&(other_town, other)) = owners.get(&neighbour) else {
+                    continue;
+                };
+                if other == nation {
+                    continue;
+                }
+                *edges
+                    .entry((nation.min(other), nation.max(other)))
+                    .or_default() += 1;
+                frontier.entry(town).or_default().insert(other);
+                frontier.entry(other_town).or_default().insert(nation);
+            }
+        }
+
+        let name = |uuid: NationId| members[&uuid].0.clone();
+        let borders = edges
+            .into_iter()
+            .map(|((a, b), edges)| Border {
+                nations: [name(a), name(b)],
+                edges,
+            })
+            .collect();
+
+        let mut frontier_towns = Vec::new();
+        for (nation, towns) in members.values() {
+            for town in towns {
+                let Some(neighbours) = frontier.get(&town.uuid) else {
+                    continue;
+                };
+                frontier_towns.push(FrontierTown {
+                    town: named_town(town),
+                    nation: nation.clone(),
+                    neighbours: neighbours.iter().map(|&n| name(n)).collect(),
+                });
+            }
+        }
+
+        let mut nations: Vec<NationTerritory> = members
+            .into_values()
+            .map(|(nation, towns)| NationTerritory {
+                nation,
+                towns: towns.iter().map(|town| named_town(town)).collect(),
+                shape: ClaimShape::from_chunks(
+                    towns
+                        .iter()
+                        .flat_map(|town| town.coordinates.town_blocks.iter())
+                        .copied(),
+                ),
+            })
+            .collect();
+        nations.sort_by_key(|territory| Reverse(territory.area()));
+
+        Self {
+            nations,
+            borders,
+            frontier: frontier_towns,
+        }
+    }
+
+    /// Every nation's territory, largest first.
+    pub fn nations(&self) -> &[NationTerritory] {
+        &self.nations
+    }
+
+    pub fn nation(&self, nation: NationId) -> Option<&NationTerritory> {
+        self.nations.iter().find(|t| t.nation.uuid == nation)
+    }
+
+    /// Every border, ordered by the IDs of the nations on it.
+    pub fn borders(&self) -> &[Border] {
+        &self.borders
+    }
+
+    /// The borders `nation` shares with other nations.
+    pub fn borders_of(
+        &self,
+        nation: NationId,
+    ) -> impl Iterator<Item = &Border> + '_ {
+        self.borders
+            .iter()
+            .filter(move |border| border.involves(nation))
+    }
+
+    /// The border between two nations, if they share one.
+    pub fn border_between(&self, a: NationId, b: NationId) -> Option<&Border> {
+        self.borders_of(a)
+            .find(|border| border.other(a).is_some_and(|n| n.uuid == b))
+    }
+
+    /// The nations `nation` shares a border with.
+    pub fn neighbours(
+        &self,
+        nation: NationId,
+    ) -> impl Iterator<Item = &NamedId<Nation>> + '_ {
+        self.borders_of(nation)
+            .filter_map(move |border| border.other(nation))
+    }
+
+    /// The towns next to another nation's territory, grouped by nation.
+    pub fn frontier_towns(&self) -> impl Iterator<Item = &FrontierTown> + '_ {
+        self.frontier.iter()
+    }
+
+    /// Whether `town` is next to another nation's territory.
+    pub fn is_frontier(&self, town: TownId) -> bool {
+        self.frontier.iter().any(|f| f.town.uuid == town)
+    }
+}
+
+fn named_town(town: &Town) -> NamedId<Town> {
+    NamedId {
+        name: town.name.clone(),
+        uuid: town.uuid,
+    }
+}
@@ -0,0 +1,157 @@
+//! # Timestamp
+//!
+//! Defines the [`Timestamp`] struct used for every point in time reported by
+//! the API.
+//!
+//! The API sends Unix timestamps in milliseconds. [`Timestamp`] keeps that
+//! wire format but makes the unit explicit, and converts to
+//! [`SystemTime`], or to `chrono` and `time` date-times when the crate's
+//! `chrono` or `time` features are enabled.
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A point in time, stored as milliseconds since the Unix epoch.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts-rs", ts(type = "number"))]
+#[serde(transparent)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    /// Creates a timestamp from milliseconds since the Unix epoch.
+    pub const fn from_millis(millis: i64) -> Self {
+        Self(millis)
+    }
+
+    /// The number of milliseconds since the Unix epoch.
+    pub const fn as_millis(&self) -> i64 {
+        self.0
+    }
+
+    /// The current time.
+    pub fn now() -> Self {
+        SystemTime::now().into()
+    }
+
+    /// Converts to a [`SystemTime`].
+    pub fn to_system_time(&self) -> SystemTime {
+        let offset = Duration::from_millis(self.0.unsigned_abs());
+        if self.0 >= 0 {
+            UNIX_EPOCH + offset
+        } else {
+            UNIX_EPOCH - offset
+        }
+    }
+
+    /// The time elapsed from `earlier` to this timestamp, or [`None`] if
+    /// `earlier` is later.
+    pub fn duration_since(&self, earlier: Timestamp) -> Option<Duration> {
+        let millis = self.0.checked_sub(earlier.0)?;
+        u64::try_from(millis).ok().map(Duration::from_millis)
+    }
+
+    /// The time elapsed since this timestamp, or zero if it is in the
+    /// future.
+    pub fn elapsed(&self) -> Duration {
+        Self::now().duration_since(*self).unwrap_or_default()
+    }
+
+    /// Converts to a `chrono` date-time in UTC, saturating at the limits of
+    /// its range.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::from_timestamp_millis(self.0).unwrap_or(
+            if self.0 < 0 {
+                chrono::DateTime::<chrono::Utc>::MIN_UTC
+            } else {
+                chrono::DateTime::<chrono::Utc>::MAX_UTC
+            },
+        )
+    }
+
+    /// Converts to a `time` date-time in UTC, saturating at the limits of
+    /// its range.
+    #[cfg(feature = "time")]
+    pub fn to_offset_date_time(&self) -> time::OffsetDateTime {
+        let nanos = i128::from(self.0) * 1_000_000;
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap_or(
+            if self.0 < 0 {
+                time::PrimitiveDateTime::MIN.assume_utc()
+            } else {
+                time::PrimitiveDateTime::MAX.assume_utc()
+            },
+        )
+    }
+}
+
+/// Formats as the raw number of milliseconds, as sent by the API.
+impl fmt::Debug for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_system_time()
+    }
+}
+
+/// Truncates to whole milliseconds, saturating at the limits of the range.
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_millis()).unwrap_or(i64::MAX),
+            Err(before) => i64::try_from(before.duration().as_millis())
+                .map(|millis| -millis)
+                .unwrap_or(i64::MIN),
+        };
+        Self(millis)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_chrono()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Timestamp {
+    fn from(time: chrono::DateTime<Tz>) -> Self {
+        Self(time.timestamp_millis())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_offset_date_time()
+    }
+}
+
+/// Truncates to whole milliseconds.
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Timestamp {
+    fn from(time: time::OffsetDateTime) -> Self {
+        let millis = time.unix_timestamp_nanos().div_euclid(1_000_000);
+        Self(millis as i64)
+    }
+}
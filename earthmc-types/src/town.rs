@@ -1,24 +1,31 @@
 //! # Town
 //!
 //! Defines the [`Town`] struct.
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
 use crate::{
+    id::TownId,
     named_id::{NamedId, NamedIdOpt},
+    nation::Nation,
     permission::TownyPermissions,
+    player::Player,
+    position::ChunkPos,
+    quarter::Quarter,
+    timestamp::Timestamp,
     world_location::WorldLocation,
 };
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct Town {
     /// The town's name.
     pub name: String,
-    /// The town's [`Uuid`].
-    pub uuid: Uuid,
+    /// The town's ID.
+    pub uuid: TownId,
     /// The town's board as seen on `/t` in-game.
     pub board: Option<String>,
     /// The founder of the town as seen on `/t` in-game.
@@ -26,38 +33,53 @@ pub struct Town {
     /// The town's wiki URL.
     pub wiki: Option<String>,
     /// The town's mayor.
-    pub mayor: NamedId,
+    pub mayor: NamedId<Player>,
     /// The town's nation.
-    pub nation: NamedIdOpt,
+    pub nation: NamedIdOpt<Nation>,
     pub timestamps: TownTimestamps,
     pub status: TownStatus,
     pub stats: TownStats,
     pub perms: TownyPermissions,
     pub coordinates: TownCoordinates,
     /// A list of all of the residents of the town.
-    pub residents: Vec<NamedId>,
+    pub residents: Vec<NamedId<Player>>,
     /// A list of all of the trusted residents of the town.
-    pub trusted: Vec<NamedId>,
+    pub trusted: Vec<NamedId<Player>>,
     /// A list of all of the outlawed people of the town.
-    pub outlaws: Vec<NamedId>,
+    pub outlaws: Vec<NamedId<Player>>,
     /// A list of every Quarter in the town.
-    pub quarters: Vec<NamedId>,
+    pub quarters: Vec<NamedId<Quarter>>,
     /// Lists of everyone in every rank in the town.
-    pub ranks: BTreeMap<TownRankKind, Vec<NamedId>>,
+    pub ranks: BTreeMap<TownRankKind, Vec<NamedId<Player>>>,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+impl Town {
+    /// The time elapsed since the town was created.
+    pub fn age(&self) -> Duration {
+        self.timestamps.registered.elapsed()
+    }
+}
+
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct TownTimestamps {
-    /// Unix timestamp representing when the town was created.
-    pub registered: i64,
-    /// Unix timestamp representing when the town joined its current nation.
-    pub joined_nation_at: i64,
-    /// Unix timestamp representing when the town fell into ruin.
-    pub ruined_at: Option<i64>,
+    /// When the town was created.
+    pub registered: Timestamp,
+    /// When the town joined its current nation.
+    pub joined_nation_at: Timestamp,
+    /// When the town fell into ruin.
+    pub ruined_at: Option<Timestamp>,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(
+    Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, Default,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct TownStatus {
     /// If the town is public.
@@ -82,7 +104,9 @@ pub struct TownStatus {
     pub can_outsiders_spawn: bool,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct TownStats {
     /// The total number of town blocks the town has.
@@ -103,18 +127,33 @@ pub struct TownStats {
     pub for_sale_price: Option<f64>,
 }
 
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "camelCase")]
 pub struct TownCoordinates {
     /// The locatioin of the town's spawn point.
     pub spawn: WorldLocation,
-    pub home_block: [i32; 2],
-    pub town_blocks: Vec<[i32; 2]>,
+    /// The chunk containing the town's home block.
+    pub home_block: ChunkPos,
+    /// Every chunk claimed by the town.
+    pub town_blocks: Vec<ChunkPos>,
 }
 
 #[derive(
-    Deserialize, Serialize, PartialEq, Eq, Hash, PartialOrd, Ord, Debug,
+    Deserialize,
+    Serialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
 #[serde(rename_all = "PascalCase")]
 pub enum TownRankKind {
     Councillor,
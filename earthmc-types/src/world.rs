@@ -0,0 +1,47 @@
+//! # World
+//!
+//! Defines the [`World`] enum and the [`WorldTagged`] wrapper.
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum World {
+    #[default]
+    Aurora,
+    Other(String),
+}
+
+impl World {
+    pub fn as_string(&self) -> String {
+        match self {
+            World::Aurora => "aurora".to_owned(),
+            World::Other(name) => name.clone(),
+        }
+    }
+}
+
+impl fmt::Display for World {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_string())
+    }
+}
+
+/// A value tagged with the [`World`] it was fetched from.
+///
+/// Returned by the multi-world helpers on the `earthmc` client.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorldTagged<T> {
+    /// The world the value came from.
+    pub world: World,
+    /// The value itself.
+    pub value: T,
+}
+
+impl<T> WorldTagged<T> {
+    /// Maps the tagged value, keeping the world.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> WorldTagged<U> {
+        WorldTagged {
+            world: self.world,
+            value: f(self.value),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use earthmc::{
+    Client,
+    nearby::{DistanceTo, Nearby, NearbySearch},
+    position::BlockPosXZ,
+    query::{
+        NearbyQueryBuilder, NearbyQueryItem, NearbyQueryItemBuilder,
+        NearbySearchType, NearbyTarget, NearbyTargetType,
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::default();
+
+    let items: Vec<NearbyQueryItem> = vec![
+        NearbyQueryItemBuilder::default()
+            .target_type(NearbyTargetType::Town)
+            .target(NearbyTarget::Town("Berlin".to_string()))
+            .search_type(NearbySearchType::Town)
+            .radius(500)
+            .build()?,
+        NearbyQueryItemBuilder::default()
+            .target_type(NearbyTargetType::Coordinate)
+            .target(NearbyTarget::Coordinates(BlockPosXZ::new(0, 0)))
+            .search_type(NearbySearchType::Town)
+            .radius(1500)
+            .build()?,
+    ];
+    let query = items
+        .iter()
+        .cloned()
+        .fold(NearbyQueryBuilder::default(), |query, item| {
+            query.insert(item)
+        })
+        .build()?;
+    let responses = client.nearby(query).await?;
+
+    let all = client.all_towns().await?;
+    let towns = client
+        .towns_bulk(all.into_iter().map(|town| town.uuid))
+        .await?;
+    let nearby = Nearby::new(&towns);
+
+    // the endpoint's results against the offline engine, for every metric
+    for (item, response) in items.iter().zip(&responses) {
+        let expected: Vec<&str> =
+            response.iter().map(|town| town.name.as_str()).collect();
+        println!("{:?} ({} towns):", item.target, expected.len());
+
+        for to in [
+            DistanceTo::HomeBlock,
+            DistanceTo::Spawn,
+            DistanceTo::NearestChunk,
+        ] {
+            let search = NearbySearch {
+                to,
+                ..NearbySearch::from(item)
+            };
+            let Some(found) = nearby.query(&search) else {
+                println!("  the target town wasn't found");
+                break;
+            };
+            let names: Vec<&str> =
+                found.iter().map(|t| t.town.name.as_str()).collect();
+            let verdict = if names == expected {
+                "same towns in the same order"
+            } else if names.len() == expected.len()
+                && names.iter().all(|name| expected.contains(name))
+            {
+                "same towns in a different order"
+            } else {
+                "different towns"
+            };
+            println!("  {to:?}: {verdict}");
+        }
+    }
+
+    Ok(())
+}
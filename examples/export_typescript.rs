@@ -0,0 +1,26 @@
+use earthmc::{
+    discord_link::DiscordLink, location::LocationInfo,
+    mystery_master::MysteryMaster, nation::Nation, player::Player,
+    player_stats::StatMap, quarter::Quarter, server::Server, town::Town,
+};
+use ts_rs::TS;
+
+/// Writes a `.ts` file for every model into the directory given as the first
+/// argument, or `bindings` if there is none.
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let out_dir = std::env::args().nth(1).unwrap_or("bindings".to_owned());
+
+    DiscordLink::export_all_to(&out_dir)?;
+    LocationInfo::export_all_to(&out_dir)?;
+    MysteryMaster::export_all_to(&out_dir)?;
+    Nation::export_all_to(&out_dir)?;
+    Player::export_all_to(&out_dir)?;
+    StatMap::export_all_to(&out_dir)?;
+    Quarter::export_all_to(&out_dir)?;
+    Server::export_all_to(&out_dir)?;
+    Town::export_all_to(&out_dir)?;
+
+    println!("Exported TypeScript definitions to {out_dir}");
+
+    Ok(())
+}
@@ -14,8 +14,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let stats = client.player_stats().await?;
 
-    for stat in &stats.0 {
-        println!("{:?}", stat);
+    for (stat, value) in stats.iter() {
+        println!("{}: {}", stat.label(), stat.format_value(value));
+    }
+
+    if let Some(play_time) = stats.play_time() {
+        println!("\nTotal play time: {} hours", play_time.as_secs() / 3600);
     }
 
     Ok(())
@@ -0,0 +1,32 @@
+use earthmc::Client;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::default();
+
+    let all = client.all_towns().await?;
+
+    // split into chunks and fetched concurrently, backing off if the API
+    // starts rate limiting
+    let towns = client
+        .towns_bulk(all.into_iter().map(|town| town.uuid))
+        .await?;
+
+    let richest = towns
+        .iter()
+        .max_by(|a, b| a.stats.balance.total_cmp(&b.stats.balance));
+    if let Some(town) = richest {
+        println!(
+            "The richest town is {} with {}G.",
+            town.name, town.stats.balance
+        );
+    }
+
+    println!(
+        "Fetched {} towns, ending with a concurrency limit of {}.",
+        towns.len(),
+        client.concurrency().limit()
+    );
+
+    Ok(())
+}
@@ -2,9 +2,9 @@ use std::str::FromStr;
 
 use earthmc::{
     Client,
+    id::PlayerId,
     query::{DiscordQueryBuilder, DiscordQueryItem},
 };
-use uuid::Uuid;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -12,7 +12,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let query = DiscordQueryBuilder::default()
         .insert(DiscordQueryItem::Minecraft {
-            target: Uuid::from_str("f17d77ab-aed4-44e7-96ef-ec9cd473eda3")?,
+            target: PlayerId::from_str("f17d77ab-aed4-44e7-96ef-ec9cd473eda3")?,
         })
         .insert(DiscordQueryItem::Discord {
             target: "160374716928884736".into(),
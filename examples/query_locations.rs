@@ -1,12 +1,12 @@
-use earthmc::{Client, query::LocationQueryBuilder};
+use earthmc::{Client, position::BlockPosXZ, query::LocationQueryBuilder};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = Client::default();
 
     let query = LocationQueryBuilder::default()
-        .insert([0, 0])
-        .insert([100, 100])
+        .insert(BlockPosXZ::new(0, 0))
+        .insert(BlockPosXZ::new(100, 100))
         .build()?;
 
     let locations = client.locations(query).await?;
@@ -1,5 +1,6 @@
 use earthmc::{
     Client,
+    position::BlockPosXZ,
     query::{
         NearbyQueryBuilder, NearbyQueryItemBuilder, NearbySearchType,
         NearbyTarget, NearbyTargetType,
@@ -22,7 +23,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .insert(
             NearbyQueryItemBuilder::default()
                 .target_type(NearbyTargetType::Coordinate)
-                .target(NearbyTarget::Coordinates([0, 0]))
+                .target(NearbyTarget::Coordinates(BlockPosXZ::new(0, 0)))
                 .search_type(NearbySearchType::Town)
                 .radius(1500)
                 .build()?,
@@ -0,0 +1,38 @@
+use earthmc::{Client, query::SimpleQueryBuilder, world::World};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = Client::default();
+
+    // a view of the same client that targets another world
+    let nostra = client.world(World::Other("nostra".to_string()));
+    println!(
+        "Querying {} and {}",
+        client.current_world(),
+        nostra.current_world()
+    );
+
+    let results = client
+        .across_worlds(
+            [World::Aurora, World::Other("nostra".to_string())],
+            |client| async move {
+                let query = SimpleQueryBuilder::default()
+                    .insert("London")
+                    .build()
+                    .expect("query should be valid");
+                client.towns(query).await
+            },
+        )
+        .await;
+
+    for tagged in results {
+        match tagged.value {
+            Ok(towns) => {
+                println!("{}: found {} town(s)", tagged.world, towns.len())
+            }
+            Err(e) => println!("{}: request failed: {e}", tagged.world),
+        }
+    }
+
+    Ok(())
+}
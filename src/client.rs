@@ -5,10 +5,11 @@
 //! Contains the [`Client`] struct and its methods.
 
 use parking_lot::Mutex;
-use reqwest::{Client as ReqwestClient, Url};
+use reqwest::{Client as ReqwestClient, Method, Url};
 use serde::{Serialize, de::DeserializeOwned};
 use std::{
     fmt::Debug,
+    future::Future,
     sync::{Arc, LazyLock},
     time::Duration,
 };
@@ -16,26 +17,38 @@ use std::{
 use derive_builder::Builder;
 
 use crate::{
+    concurrency::AdaptiveConcurrency,
     discord_link::DiscordLink,
+    endpoint::{
+        EndpointHealth, Endpoints, FAILOVER_COOLDOWN, is_endpoint_failure,
+    },
     errors::{Error, snippet_around},
+    id::QuarterId,
     location::LocationInfo,
     mystery_master::MysteryMaster,
     named_id::NamedId,
     nation::Nation,
     player::Player,
     player_stats::StatMap,
+    prepared::{HttpMethod, PreparedRequest},
     quarter::Quarter,
     query::{
-        DiscordQuery, LocationQuery, NearbyQuery, Query, SimpleQuery, UuidQuery,
+        DiscordQuery, LocationQuery, NationQuery, NearbyQuery, PlayerQuery,
+        Query, SimpleQuery, SimpleQueryBuilder, StrOrId, TownQuery, UuidQuery,
+        UuidQueryBuilder,
     },
     retry_strategy::{JitteredBackoff, RetryStrategy},
     server::Server,
     town::Town,
-    world::World,
+    world::{World, WorldTagged},
 };
 
 pub const DEFAULT_BASE_URL: &str = "https://api.earthmc.net/v3/";
 
+/// The maximum number of values sent in a single query by the bulk
+/// operations.
+pub const MAX_QUERY_SIZE: usize = 100;
+
 static DEFAULT_HTTP_CLIENT: LazyLock<ReqwestClient> = LazyLock::new(|| {
     reqwest::ClientBuilder::new()
         .connect_timeout(Duration::from_secs(10))
@@ -52,23 +65,77 @@ static DEFAULT_HTTP_CLIENT: LazyLock<ReqwestClient> = LazyLock::new(|| {
 pub struct Client {
     #[builder(default = DEFAULT_HTTP_CLIENT.clone())]
     reqwest_client: ReqwestClient,
-    #[builder(default = DEFAULT_BASE_URL.parse().unwrap())]
-    base_url: Url,
+    /// The ordered list of base URLs requests are sent to. See
+    /// [`ClientBuilder::base_url`].
+    #[builder(
+        setter(custom),
+        default = Arc::new(Endpoints::new(vec![DEFAULT_BASE_URL.parse().unwrap()]))
+    )]
+    endpoints: Arc<Endpoints>,
+    /// How long an endpoint is skipped after a failed request before it is
+    /// preferred again.
+    #[builder(default = FAILOVER_COOLDOWN)]
+    failover_cooldown: Duration,
     #[builder(default = Arc::new(Mutex::new(JitteredBackoff::default())))]
     retry_strategy: Arc<Mutex<dyn RetryStrategy>>,
     #[builder(default = World::Aurora)]
     world: World,
+    /// The controller limiting the concurrency of bulk operations.
+    #[builder(default = Arc::new(AdaptiveConcurrency::default()))]
+    concurrency: Arc<AdaptiveConcurrency>,
 }
 
 impl Debug for Client {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Client")
             .field("reqwest_client", &self.reqwest_client)
-            .field("base_url", &self.base_url)
+            .field("base_urls", &self.endpoints.urls())
+            .field("world", &self.world)
             .finish()
     }
 }
 
+impl ClientBuilder {
+    /// Adds a base URL to the ordered list of endpoints requests are sent to.
+    ///
+    /// Requests go to the first healthy endpoint. When a request fails because
+    /// of the endpoint and the retry strategy allows another attempt, it is
+    /// retried on the next endpoint in the list that isn't cooling down after
+    /// a failure. See [`endpoint`](crate::endpoint) for details. Defaults to [`DEFAULT_BASE_URL`] when no base URL
+    /// is added.
+    ///
+    /// ```rust
+    /// # use earthmc::ClientBuilder;
+    /// #
+    /// let client = ClientBuilder::default()
+    ///     .base_url("https://api.earthmc.net/v3/".parse().unwrap())
+    ///     .base_url("http://localhost:8080/v3/".parse().unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn base_url(&mut self, url: Url) -> &mut Self {
+        let mut urls = self
+            .endpoints
+            .as_ref()
+            .map(|endpoints| endpoints.urls())
+            .unwrap_or_default();
+        urls.push(url);
+        self.endpoints = Some(Arc::new(Endpoints::new(urls)));
+        self
+    }
+
+    /// Adds several base URLs, in order. See [`ClientBuilder::base_url`].
+    pub fn base_urls<I>(&mut self, urls: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Url>,
+    {
+        for url in urls {
+            self.base_url(url);
+        }
+        self
+    }
+}
+
 impl Default for Client {
     fn default() -> Self {
         ClientBuilder::default()
@@ -78,60 +145,120 @@ impl Default for Client {
 }
 
 impl Client {
+    /// Returns a view of this client that sends its requests to `world`.
+    ///
+    /// The view shares the HTTP connection pool and the retry strategy with
+    /// `self`, so creating one is cheap.
+    ///
+    /// ```rust
+    /// # use earthmc::{Client, world::World};
+    /// #
+    /// let client = Client::default();
+    /// let nostra = client.world(World::Other("nostra".to_string()));
+    /// ```
+    pub fn world(&self, world: World) -> Client {
+        Client {
+            world,
+            ..self.clone()
+        }
+    }
+
+    /// The world this client sends its requests to.
+    pub fn current_world(&self) -> &World {
+        &self.world
+    }
+
+    /// Request counters for each of the client's base URLs, in the order they
+    /// were configured.
+    ///
+    /// The counters are shared with every world view of this client.
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
+    /// The adaptive concurrency controller used by the bulk operations.
+    ///
+    /// It is shared with every world view of this client.
+    pub fn concurrency(&self) -> &AdaptiveConcurrency {
+        &self.concurrency
+    }
+
+    /// Runs the same request on several worlds concurrently.
+    ///
+    /// `f` is called once per world with a view of this client targeting that
+    /// world (see [`Client::world`]). The results are returned in the order
+    /// the worlds were given, each tagged with the world it came from.
+    ///
+    /// ```rust,no_run
+    /// # use earthmc::{Client, world::World};
+    /// # async fn run() {
+    /// let client = Client::default();
+    /// let servers = client
+    ///     .across_worlds(
+    ///         [World::Aurora, World::Other("nostra".to_string())],
+    ///         |client| async move { client.server().await },
+    ///     )
+    ///     .await;
+    ///
+    /// for tagged in servers {
+    ///     println!("{}: {:?}", tagged.world, tagged.value.is_ok());
+    /// }
+    /// # }
+    /// ```
+    pub async fn across_worlds<I, F, Fut, T>(
+        &self,
+        worlds: I,
+        f: F,
+    ) -> Vec<WorldTagged<Result<T, Error>>>
+    where
+        I: IntoIterator<Item = World>,
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let requests = worlds.into_iter().map(|world| {
+            let request = f(self.world(world.clone()));
+            async move {
+                WorldTagged {
+                    world,
+                    value: request.await,
+                }
+            }
+        });
+
+        futures_util::future::join_all(requests).await
+    }
+
+    /// Like [`Client::across_worlds`], but fails with the first error
+    /// encountered instead of returning a result per world.
+    pub async fn try_across_worlds<I, F, Fut, T>(
+        &self,
+        worlds: I,
+        f: F,
+    ) -> Result<Vec<WorldTagged<T>>, Error>
+    where
+        I: IntoIterator<Item = World>,
+        F: Fn(Client) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        self.across_worlds(worlds, f)
+            .await
+            .into_iter()
+            .map(|tagged| {
+                Ok(WorldTagged {
+                    world: tagged.world,
+                    value: tagged.value?,
+                })
+            })
+            .collect()
+    }
+
     /// Perform a GET request and deserialize into `T`.
     async fn get<T>(&self, path: &str) -> Result<T, Error>
     where
         T: DeserializeOwned,
     {
-        // build the full URL
-        let combined = format!("{}/{}", self.world.as_string(), path);
-        let url = self
-            .base_url
-            .join(&combined)
-            .expect("Failed to construct request URL");
-
-        let mut num_retries = 0;
-        loop {
-            let attempt = self.reqwest_client.get(url.clone()).send().await;
-
-            match attempt {
-                Ok(response) => {
-                    match response.error_for_status() {
-                        Ok(success) => {
-                            // deserialize into T
-                            let parsed = success.json::<T>().await?;
-                            return Ok(parsed);
-                        }
-                        Err(e) => {
-                            let err: Error = e.into();
-                            let delay_opt = {
-                                let mut strat = self.retry_strategy.lock();
-                                strat.should_retry_after(num_retries)
-                            };
-                            if let Some(delay) = delay_opt {
-                                tokio::time::sleep(delay).await;
-                                num_retries += 1;
-                                continue;
-                            }
-                            return Err(err);
-                        }
-                    }
-                }
-                Err(e) => {
-                    let err: Error = e.into();
-                    let delay_opt = {
-                        let mut strat = self.retry_strategy.lock();
-                        strat.should_retry_after(num_retries)
-                    };
-                    if let Some(delay) = delay_opt {
-                        tokio::time::sleep(delay).await;
-                        num_retries += 1;
-                        continue;
-                    }
-                    return Err(err);
-                }
-            }
-        }
+        self.send::<T, ()>(Method::GET, RequestTarget::Path(path), None, None)
+            .await
     }
 
     /// Perform a POST request with JSON body `B` and deserializes the response into
@@ -141,59 +268,197 @@ impl Client {
         T: DeserializeOwned,
         B: Serialize + Sized,
     {
-        // build full URL
+        self.send::<T, B>(
+            Method::POST,
+            RequestTarget::Path(path),
+            Some(&body),
+            None,
+        )
+        .await
+    }
+
+    /// Splits `values` into queries of at most [`MAX_QUERY_SIZE`] values and
+    /// POSTs them concurrently, limited by the client's adaptive concurrency
+    /// controller. The results are concatenated in order.
+    async fn post_chunked<T, V, Q, F>(
+        &self,
+        path: &str,
+        values: Vec<V>,
+        make_query: F,
+    ) -> Result<Vec<T>, Error>
+    where
+        T: DeserializeOwned,
+        Q: Serialize,
+        F: Fn(Vec<V>) -> Q,
+    {
+        let mut chunks: Vec<Vec<V>> = Vec::new();
+        for value in values {
+            match chunks.last_mut() {
+                Some(chunk) if chunk.len() < MAX_QUERY_SIZE => {
+                    chunk.push(value)
+                }
+                _ => chunks.push(vec![value]),
+            }
+        }
+
+        let requests = chunks.into_iter().map(|chunk| {
+            let query = Query::from(make_query(chunk));
+            async move {
+                self.send::<Vec<T>, Query<Q>>(
+                    Method::POST,
+                    RequestTarget::Path(path),
+                    Some(&query),
+                    Some(&self.concurrency),
+                )
+                .await
+            }
+        });
+
+        let results = futures_util::future::try_join_all(requests).await?;
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    /// Joins `path` onto `base_url`, prefixed with the client's world.
+    fn resolve_url(&self, base_url: &Url, path: &str) -> Url {
         let combined = format!("{}/{}", self.world.as_string(), path);
-        let url = self
-            .base_url
+        base_url
             .join(&combined)
-            .expect("Failed to construct request URL");
+            .expect("Failed to construct request URL")
+    }
+
+    /// Builds a request to the preferred endpoint without sending it.
+    fn prepare<T, B>(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<B>,
+    ) -> PreparedRequest<T>
+    where
+        B: Serialize,
+    {
+        let endpoint = self.endpoints.get(self.endpoints.preferred());
+        let body = body.map(|body| {
+            serde_json::to_value(body).expect("Queries should serialize")
+        });
+        PreparedRequest::new(
+            method,
+            self.resolve_url(endpoint.url(), path),
+            body,
+        )
+    }
+
+    /// Sends a previously prepared request and deserializes the response into
+    /// `T`.
+    ///
+    /// The request is sent to its exact URL, so it is retried according to
+    /// the retry strategy but does not fail over to other base URLs.
+    ///
+    /// ```rust,no_run
+    /// # use earthmc::{Client, query::SimpleQueryBuilder};
+    /// # async fn run() -> Result<(), earthmc::errors::Error> {
+    /// let client = Client::default();
+    /// let query = SimpleQueryBuilder::default().insert("London").build().unwrap();
+    ///
+    /// let request = client.prepare_towns(query);
+    /// println!("{request}");
+    ///
+    /// let towns = client.execute(&request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute<T>(
+        &self,
+        request: &PreparedRequest<T>,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        self.send::<T, serde_json::Value>(
+            request.method.into(),
+            RequestTarget::Url(&request.url),
+            request.body.as_ref(),
+            None,
+        )
+        .await
+    }
+
+    /// Sends a request to the preferred endpoint, retrying on the next
+    /// endpoints for as long as the retry strategy allows.
+    ///
+    /// When a `limiter` is given, every attempt waits for a permit from it
+    /// and reports its outcome back.
+    async fn send<T, B>(
+        &self,
+        method: Method,
+        target: RequestTarget<'_>,
+        body: Option<&B>,
+        limiter: Option<&AdaptiveConcurrency>,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let mut current = self.endpoints.preferred();
 
         let mut num_retries = 0;
         loop {
-            let attempt = self
-                .reqwest_client
-                .post(url.clone())
-                .json(&body)
-                .send()
-                .await;
+            let (endpoint, url) = match target {
+                RequestTarget::Path(path) => {
+                    let endpoint = self.endpoints.get(current);
+                    (Some(endpoint), self.resolve_url(endpoint.url(), path))
+                }
+                RequestTarget::Url(url) => (None, url.clone()),
+            };
 
-            match attempt {
+            let mut request = self.reqwest_client.request(method.clone(), url);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+
+            let permit = match limiter {
+                Some(limiter) => Some(limiter.acquire().await),
+                None => None,
+            };
+            let attempt = match request.send().await {
                 Ok(response) => match response.error_for_status() {
-                    Ok(ok_response) => {
-                        let text = ok_response.text().await?;
-                        match serde_json::from_str::<T>(&text) {
-                            Ok(parsed) => return Ok(parsed),
-                            Err(de_err) => {
-                                let snippet = snippet_around(
-                                    &text,
-                                    de_err.line(),
-                                    de_err.column(),
-                                    10,
-                                );
-                                return Err(
-                                    Error::DeserializationWithSnippet {
-                                        source: de_err,
-                                        snippet,
-                                    },
-                                );
-                            }
-                        }
+                    Ok(response) => response.text().await,
+                    Err(e) => Err(e),
+                },
+                Err(e) => Err(e),
+            };
+
+            match attempt {
+                Ok(text) => {
+                    if let Some(endpoint) = endpoint {
+                        endpoint.record_success();
                     }
-                    Err(e) => {
-                        let err: Error = e.into();
-                        let delay_opt = {
-                            let mut strat = self.retry_strategy.lock();
-                            strat.should_retry_after(num_retries)
-                        };
-                        if let Some(delay) = delay_opt {
-                            tokio::time::sleep(delay).await;
-                            num_retries += 1;
-                            continue;
-                        }
-                        return Err(err);
+                    if let Some(permit) = permit {
+                        permit.record(Ok(()));
                     }
-                },
+                    return serde_json::from_str::<T>(&text).map_err(
+                        |de_err| {
+                            let snippet = snippet_around(
+                                &text,
+                                de_err.line(),
+                                de_err.column(),
+                                10,
+                            );
+                            Error::DeserializationWithSnippet {
+                                source: de_err,
+                                snippet,
+                            }
+                        },
+                    );
+                }
                 Err(e) => {
+                    let endpoint_failed = is_endpoint_failure(&e);
+                    if let Some(endpoint) = endpoint.filter(|_| endpoint_failed)
+                    {
+                        endpoint.record_failure(self.failover_cooldown);
+                    }
+                    if let Some(permit) = permit {
+                        permit.record(Err(&e));
+                    }
                     let err: Error = e.into();
                     let delay_opt = {
                         let mut strat = self.retry_strategy.lock();
@@ -202,6 +467,11 @@ impl Client {
                     if let Some(delay) = delay_opt {
                         tokio::time::sleep(delay).await;
                         num_retries += 1;
+                        // a rejected request is retried on the same
+                        // endpoint, since another one won't do better
+                        if endpoint_failed {
+                            current = self.endpoints.failover(current);
+                        }
                         continue;
                     }
                     return Err(err);
@@ -227,60 +497,99 @@ impl Client {
     }
 
     /// Fetches all currently registered Towny towns.
-    pub async fn all_towns(&self) -> Result<Vec<NamedId>, Error> {
-        self.get::<Vec<NamedId>>(Self::TOWNS_PATH).await
+    pub async fn all_towns(&self) -> Result<Vec<NamedId<Town>>, Error> {
+        self.get::<Vec<NamedId<Town>>>(Self::TOWNS_PATH).await
     }
 
     /// Queries detailed information on specific towns.
-    pub async fn towns(&self, query: SimpleQuery) -> Result<Vec<Town>, Error> {
-        self.post::<Vec<Town>, Query<SimpleQuery>>(
+    pub async fn towns(&self, query: TownQuery) -> Result<Vec<Town>, Error> {
+        self.post::<Vec<Town>, Query<TownQuery>>(
             Self::TOWNS_PATH,
             Query::from(query),
         )
         .await
     }
 
+    /// Queries detailed information on any number of towns.
+    ///
+    /// The values are split into chunks of at most [`MAX_QUERY_SIZE`] that are
+    /// sent concurrently, as allowed by the client's adaptive concurrency
+    /// controller (see [`Client::concurrency`]).
+    pub async fn towns_bulk<I>(&self, values: I) -> Result<Vec<Town>, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<StrOrId<Town>>,
+    {
+        let values = values.into_iter().map(Into::into).collect();
+        self.post_chunked(Self::TOWNS_PATH, values, simple_query)
+            .await
+    }
+
     /// Fetches all currently registered Towny nations.
-    pub async fn all_nations(&self) -> Result<Vec<NamedId>, Error> {
-        self.get::<Vec<NamedId>>(Self::NATIONS_PATH).await
+    pub async fn all_nations(&self) -> Result<Vec<NamedId<Nation>>, Error> {
+        self.get::<Vec<NamedId<Nation>>>(Self::NATIONS_PATH).await
     }
 
     /// Queries detailed information on specific nations.
     pub async fn nations(
         &self,
-        query: SimpleQuery,
+        query: NationQuery,
     ) -> Result<Vec<Nation>, Error> {
-        self.post::<Vec<Nation>, Query<SimpleQuery>>(
+        self.post::<Vec<Nation>, Query<NationQuery>>(
             Self::NATIONS_PATH,
             Query::from(query),
         )
         .await
     }
 
+    /// Queries detailed information on any number of nations. See
+    /// [`Client::towns_bulk`].
+    pub async fn nations_bulk<I>(&self, values: I) -> Result<Vec<Nation>, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<StrOrId<Nation>>,
+    {
+        let values = values.into_iter().map(Into::into).collect();
+        self.post_chunked(Self::NATIONS_PATH, values, simple_query)
+            .await
+    }
+
     /// Fetches all currently registered Towny residents.
-    pub async fn all_players(&self) -> Result<Vec<NamedId>, Error> {
-        self.get::<Vec<NamedId>>(Self::PLAYERS_PATH).await
+    pub async fn all_players(&self) -> Result<Vec<NamedId<Player>>, Error> {
+        self.get::<Vec<NamedId<Player>>>(Self::PLAYERS_PATH).await
     }
 
     /// Queries detailed information on specific players.
     pub async fn players(
         &self,
-        query: SimpleQuery,
+        query: PlayerQuery,
     ) -> Result<Vec<Player>, Error> {
-        self.post::<Vec<Player>, Query<SimpleQuery>>(
+        self.post::<Vec<Player>, Query<PlayerQuery>>(
             Self::PLAYERS_PATH,
             Query::from(query),
         )
         .await
     }
 
+    /// Queries detailed information on any number of players. See
+    /// [`Client::towns_bulk`].
+    pub async fn players_bulk<I>(&self, values: I) -> Result<Vec<Player>, Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<StrOrId<Player>>,
+    {
+        let values = values.into_iter().map(Into::into).collect();
+        self.post_chunked(Self::PLAYERS_PATH, values, simple_query)
+            .await
+    }
+
     /// Queries all the elements of search type in a given radius of a target
     /// location of the target type.
     pub async fn nearby(
         &self,
         query: NearbyQuery,
-    ) -> Result<Vec<Vec<NamedId>>, Error> {
-        self.post::<Vec<Vec<NamedId>>, Query<NearbyQuery>>(
+    ) -> Result<Vec<Vec<NamedId<Town>>>, Error> {
+        self.post::<Vec<Vec<NamedId<Town>>>, Query<NearbyQuery>>(
             Self::NEARBY_PATH,
             Query::from(query),
         )
@@ -288,8 +597,8 @@ impl Client {
     }
 
     /// Fetches all currently registered Quarters.
-    pub async fn all_quarters(&self) -> Result<Vec<NamedId>, Error> {
-        self.get::<Vec<NamedId>>(Self::QUARTERS_PATH).await
+    pub async fn all_quarters(&self) -> Result<Vec<NamedId<Quarter>>, Error> {
+        self.get::<Vec<NamedId<Quarter>>>(Self::QUARTERS_PATH).await
     }
 
     /// Queries detailed information on specific Quarters.
@@ -304,6 +613,25 @@ impl Client {
         .await
     }
 
+    /// Queries detailed information on any number of Quarters. See
+    /// [`Client::towns_bulk`].
+    pub async fn quarters_bulk<I>(
+        &self,
+        values: I,
+    ) -> Result<Vec<Quarter>, Error>
+    where
+        I: IntoIterator<Item = QuarterId>,
+    {
+        let values = values.into_iter().collect();
+        self.post_chunked(Self::QUARTERS_PATH, values, |chunk| {
+            UuidQueryBuilder::default()
+                .values(chunk)
+                .build()
+                .expect("Chunk query should be valid")
+        })
+        .await
+    }
+
     /// Queries linked Discord accounts,
     pub async fn discord(
         &self,
@@ -340,3 +668,147 @@ impl Client {
         self.get::<StatMap>(Self::PLAYER_STATS_PATH).await
     }
 }
+
+fn simple_query<K>(values: Vec<StrOrId<K>>) -> SimpleQuery<K> {
+    SimpleQueryBuilder::default()
+        .values(values)
+        .build()
+        .expect("Chunk query should be valid")
+}
+
+/// Where a request is sent.
+#[derive(Clone, Copy)]
+enum RequestTarget<'a> {
+    /// A path relative to the world, sent to the client's endpoints with
+    /// failover.
+    Path(&'a str),
+    /// An exact URL.
+    Url(&'a Url),
+}
+
+/// Builds requests without sending them, so they can be inspected, logged and
+/// replayed later with [`Client::execute`].
+impl Client {
+    /// Prepares [`Client::server`].
+    pub fn prepare_server(&self) -> PreparedRequest<Server> {
+        self.prepare::<_, ()>(HttpMethod::Get, Self::SERVER_PATH, None)
+    }
+
+    /// Prepares [`Client::all_towns`].
+    pub fn prepare_all_towns(&self) -> PreparedRequest<Vec<NamedId<Town>>> {
+        self.prepare::<_, ()>(HttpMethod::Get, Self::TOWNS_PATH, None)
+    }
+
+    /// Prepares [`Client::towns`].
+    pub fn prepare_towns(
+        &self,
+        query: TownQuery,
+    ) -> PreparedRequest<Vec<Town>> {
+        self.prepare(
+            HttpMethod::Post,
+            Self::TOWNS_PATH,
+            Some(Query::from(query)),
+        )
+    }
+
+    /// Prepares [`Client::all_nations`].
+    pub fn prepare_all_nations(&self) -> PreparedRequest<Vec<NamedId<Nation>>> {
+        self.prepare::<_, ()>(HttpMethod::Get, Self::NATIONS_PATH, None)
+    }
+
+    /// Prepares [`Client::nations`].
+    pub fn prepare_nations(
+        &self,
+        query: NationQuery,
+    ) -> PreparedRequest<Vec<Nation>> {
+        self.prepare(
+            HttpMethod::Post,
+            Self::NATIONS_PATH,
+            Some(Query::from(query)),
+        )
+    }
+
+    /// Prepares [`Client::all_players`].
+    pub fn prepare_all_players(&self) -> PreparedRequest<Vec<NamedId<Player>>> {
+        self.prepare::<_, ()>(HttpMethod::Get, Self::PLAYERS_PATH, None)
+    }
+
+    /// Prepares [`Client::players`].
+    pub fn prepare_players(
+        &self,
+        query: PlayerQuery,
+    ) -> PreparedRequest<Vec<Player>> {
+        self.prepare(
+            HttpMethod::Post,
+            Self::PLAYERS_PATH,
+            Some(Query::from(query)),
+        )
+    }
+
+    /// Prepares [`Client::nearby`].
+    pub fn prepare_nearby(
+        &self,
+        query: NearbyQuery,
+    ) -> PreparedRequest<Vec<Vec<NamedId<Town>>>> {
+        self.prepare(
+            HttpMethod::Post,
+            Self::NEARBY_PATH,
+            Some(Query::from(query)),
+        )
+    }
+
+    /// Prepares [`Client::all_quarters`].
+    pub fn prepare_all_quarters(
+        &self,
+    ) -> PreparedRequest<Vec<NamedId<Quarter>>> {
+        self.prepare::<_, ()>(HttpMethod::Get, Self::QUARTERS_PATH, None)
+    }
+
+    /// Prepares [`Client::quarters`].
+    pub fn prepare_quarters(
+        &self,
+        query: UuidQuery,
+    ) -> PreparedRequest<Vec<Quarter>> {
+        self.prepare(
+            HttpMethod::Post,
+            Self::QUARTERS_PATH,
+            Some(Query::from(query)),
+        )
+    }
+
+    /// Prepares [`Client::discord`].
+    pub fn prepare_discord(
+        &self,
+        query: DiscordQuery,
+    ) -> PreparedRequest<Vec<DiscordLink>> {
+        self.prepare(
+            HttpMethod::Post,
+            Self::DISCORD_PATH,
+            Some(Query::from(query)),
+        )
+    }
+
+    /// Prepares [`Client::mystery_master`].
+    pub fn prepare_mystery_master(
+        &self,
+    ) -> PreparedRequest<Vec<MysteryMaster>> {
+        self.prepare::<_, ()>(HttpMethod::Get, Self::MYSTERY_MASTER_PATH, None)
+    }
+
+    /// Prepares [`Client::locations`].
+    pub fn prepare_locations(
+        &self,
+        query: LocationQuery,
+    ) -> PreparedRequest<Vec<LocationInfo>> {
+        self.prepare(
+            HttpMethod::Post,
+            Self::LOCATION_PATH,
+            Some(Query::from(query)),
+        )
+    }
+
+    /// Prepares [`Client::player_stats`].
+    pub fn prepare_player_stats(&self) -> PreparedRequest<StatMap> {
+        self.prepare::<_, ()>(HttpMethod::Get, Self::PLAYER_STATS_PATH, None)
+    }
+}
@@ -0,0 +1,250 @@
+//! # Concurrency
+//!
+//! An adaptive (AIMD) concurrency controller for bulk operations.
+//!
+//! Bulk and chunked operations such as [`Client::towns_bulk`] send many
+//! requests at once. Rather than using a fixed level of parallelism, they ask
+//! an [`AdaptiveConcurrency`] controller for a permit before every attempt.
+//! The controller grows the number of permits by one per round of fast,
+//! successful responses (additive increase), and cuts it by
+//! [`decrease_factor`](AdaptiveConcurrencyBuilder::decrease_factor) when the
+//! server answers with `429 Too Many Requests`, a `5xx` status, times out, or
+//! when latency rises well above the recent average (multiplicative
+//! decrease).
+//!
+//! Each retry made by the client's
+//! [`RetryStrategy`](crate::retry_strategy::RetryStrategy) is a new attempt
+//! and takes a new permit, so the wait between retries does not hold up other
+//! requests.
+//!
+//! [`Client::towns_bulk`]: crate::client::Client::towns_bulk
+use derive_builder::Builder;
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Weight of the newest sample in the latency moving average.
+const LATENCY_SMOOTHING: f64 = 0.2;
+
+/// Latency increases smaller than this are treated as noise.
+const LATENCY_NOISE_FLOOR: Duration = Duration::from_millis(50);
+
+/// An AIMD concurrency limiter shared by the bulk operations of a client.
+///
+/// ```rust
+/// use earthmc::concurrency::AdaptiveConcurrencyBuilder;
+///
+/// let controller = AdaptiveConcurrencyBuilder::default()
+///     .initial_limit(8_usize)
+///     .max_limit(64_usize)
+///     .build()
+///     .unwrap();
+/// assert_eq!(controller.limit(), 8);
+/// ```
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    setter(into),
+    build_fn(validate = "Self::validate")
+)]
+pub struct AdaptiveConcurrency {
+    /// The number of concurrent requests allowed before any feedback has
+    /// been received.
+    #[builder(default = 4)]
+    initial_limit: usize,
+    /// The limit never drops below this.
+    #[builder(default = 1)]
+    min_limit: usize,
+    /// The limit never grows above this.
+    #[builder(default = 32)]
+    max_limit: usize,
+    /// The factor the limit is multiplied by when the server is overloaded.
+    #[builder(default = 0.5)]
+    decrease_factor: f64,
+    /// A response that takes more than this many times the average latency
+    /// counts as overload.
+    #[builder(default = 2.0)]
+    latency_tolerance: f64,
+    #[builder(setter(skip))]
+    state: Mutex<State>,
+    #[builder(setter(skip))]
+    notify: Notify,
+}
+
+#[derive(Default)]
+struct State {
+    /// The current limit, initialized on first use.
+    limit: Option<f64>,
+    in_flight: usize,
+    latency: Option<Duration>,
+    last_decrease: Option<Instant>,
+}
+
+impl AdaptiveConcurrencyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        let min = self.min_limit.unwrap_or(1);
+        let max = self.max_limit.unwrap_or(32);
+        let initial = self.initial_limit.unwrap_or(4);
+        if min == 0 {
+            return Err("min_limit must be at least 1".into());
+        }
+        if !(min..=max).contains(&initial) {
+            return Err(
+                "initial_limit must be between min_limit and max_limit".into(),
+            );
+        }
+        let factor = self.decrease_factor.unwrap_or(0.5);
+        if !(factor > 0.0 && factor < 1.0) {
+            return Err("decrease_factor must be between 0 and 1".into());
+        }
+        if self.latency_tolerance.is_some_and(|t| t < 1.0) {
+            return Err("latency_tolerance must be at least 1".into());
+        }
+        Ok(())
+    }
+}
+
+/// Creates a new [`AdaptiveConcurrency`] starting at 4 concurrent requests,
+/// growing up to 32.
+impl Default for AdaptiveConcurrency {
+    fn default() -> Self {
+        AdaptiveConcurrencyBuilder::default()
+            .build()
+            .expect("Builder defaults are valid")
+    }
+}
+
+impl AdaptiveConcurrency {
+    /// The number of requests currently allowed to run concurrently.
+    pub fn limit(&self) -> usize {
+        let state = self.state.lock();
+        self.current_limit(&state) as usize
+    }
+
+    /// The number of permits currently held.
+    pub fn in_flight(&self) -> usize {
+        self.state.lock().in_flight
+    }
+
+    /// Waits until a request may be sent.
+    ///
+    /// Report how the request went with the returned permit. Dropping it
+    /// without reporting releases it without affecting the limit.
+    pub async fn acquire(&self) -> ConcurrencyPermit<'_> {
+        loop {
+            let notified = self.notify.notified();
+            {
+                let mut state = self.state.lock();
+                if (state.in_flight as f64) < self.current_limit(&state).floor()
+                {
+                    state.in_flight += 1;
+                    return ConcurrencyPermit {
+                        controller: self,
+                        started: Instant::now(),
+                    };
+                }
+            }
+            notified.await;
+        }
+    }
+
+    fn current_limit(&self, state: &State) -> f64 {
+        state.limit.unwrap_or(self.initial_limit as f64)
+    }
+
+    fn on_success(&self, started: Instant) {
+        let latency = started.elapsed();
+        let mut state = self.state.lock();
+
+        let slow = state.latency.is_some_and(|average| {
+            latency > average.mul_f64(self.latency_tolerance)
+                && latency - average > LATENCY_NOISE_FLOOR
+        });
+        state.latency = Some(match state.latency {
+            Some(average) => {
+                average.mul_f64(1.0 - LATENCY_SMOOTHING)
+                    + latency.mul_f64(LATENCY_SMOOTHING)
+            }
+            None => latency,
+        });
+
+        if slow {
+            self.decrease(&mut state, started);
+        } else {
+            // grow by one permit per full round of successful requests
+            let limit = self.current_limit(&state);
+            let grown = (limit + 1.0 / limit).min(self.max_limit as f64);
+            state.limit = Some(grown);
+            if grown.floor() > limit.floor() {
+                self.notify.notify_one();
+            }
+        }
+    }
+
+    fn on_overload(&self, started: Instant) {
+        let mut state = self.state.lock();
+        self.decrease(&mut state, started);
+    }
+
+    fn decrease(&self, state: &mut State, started: Instant) {
+        // requests sent before the last decrease were part of the load that
+        // caused it; counting them again would collapse the limit
+        if state.last_decrease.is_some_and(|at| started < at) {
+            return;
+        }
+        let limit = self.current_limit(state) * self.decrease_factor;
+        state.limit = Some(limit.max(self.min_limit as f64));
+        state.last_decrease = Some(Instant::now());
+    }
+
+    fn release(&self) {
+        self.state.lock().in_flight -= 1;
+        self.notify.notify_one();
+    }
+}
+
+/// Permission to send one request, obtained from
+/// [`AdaptiveConcurrency::acquire`].
+pub struct ConcurrencyPermit<'a> {
+    controller: &'a AdaptiveConcurrency,
+    started: Instant,
+}
+
+impl ConcurrencyPermit<'_> {
+    /// Reports a successful response, letting the limit grow unless it was
+    /// unusually slow.
+    pub fn success(self) {
+        self.controller.on_success(self.started);
+    }
+
+    /// Reports that the server is overloaded, shrinking the limit.
+    pub fn overloaded(self) {
+        self.controller.on_overload(self.started);
+    }
+
+    /// Reports the outcome of a request from its error, if any.
+    ///
+    /// Rate limiting (`429`), server errors (`5xx`) and timeouts count as
+    /// overload; other errors leave the limit unchanged.
+    pub fn record(self, result: Result<(), &reqwest::Error>) {
+        match result {
+            Ok(()) => self.success(),
+            Err(e) if is_overload(e) => self.overloaded(),
+            Err(_) => {}
+        }
+    }
+}
+
+impl Drop for ConcurrencyPermit<'_> {
+    fn drop(&mut self) {
+        self.controller.release();
+    }
+}
+
+fn is_overload(e: &reqwest::Error) -> bool {
+    e.is_timeout()
+        || e.status().is_some_and(|status| {
+            status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                || status.is_server_error()
+        })
+}
@@ -0,0 +1,170 @@
+//! # Endpoint
+//!
+//! Base URL failover and per-endpoint health tracking.
+//!
+//! A [`Client`](crate::client::Client) can be configured with an ordered list
+//! of base URLs, e.g. the official API followed by a local mirror. Requests go
+//! to the first healthy endpoint and, when a request fails and the retry
+//! strategy allows another attempt, the retry is sent to the next endpoint in
+//! the list that is not cooling down.
+//!
+//! Only failures that point at the endpoint itself (connection errors,
+//! timeouts, server errors and rate limiting) count against it and cause a
+//! failover. Other errors, like a 404, are retried on the same endpoint.
+use parking_lot::Mutex;
+use reqwest::Url;
+use std::time::{Duration, Instant};
+
+/// How long an endpoint is skipped after a failed request before it is
+/// preferred again, unless the client is built with a different
+/// [`failover_cooldown`](crate::ClientBuilder::failover_cooldown).
+pub const FAILOVER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// A snapshot of the request counters of a single endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointHealth {
+    /// The endpoint's base URL.
+    pub url: Url,
+    /// The total number of successful requests.
+    pub successes: u64,
+    /// The total number of failed requests.
+    pub failures: u64,
+    /// The number of requests that failed since the last successful one.
+    pub consecutive_failures: u64,
+}
+
+impl EndpointHealth {
+    /// If the last request sent to this endpoint succeeded (or it has not been
+    /// used yet).
+    pub fn is_healthy(&self) -> bool {
+        self.consecutive_failures == 0
+    }
+}
+
+#[derive(Default)]
+struct EndpointStats {
+    successes: u64,
+    failures: u64,
+    consecutive_failures: u64,
+    cooling_down_until: Option<Instant>,
+}
+
+pub(crate) struct Endpoint {
+    url: Url,
+    stats: Mutex<EndpointStats>,
+}
+
+impl Endpoint {
+    pub(crate) fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub(crate) fn record_success(&self) {
+        let mut stats = self.stats.lock();
+        stats.successes += 1;
+        stats.consecutive_failures = 0;
+        stats.cooling_down_until = None;
+    }
+
+    pub(crate) fn record_failure(&self, cooldown: Duration) {
+        let mut stats = self.stats.lock();
+        stats.failures += 1;
+        stats.consecutive_failures += 1;
+        stats.cooling_down_until = Some(Instant::now() + cooldown);
+    }
+
+    fn health(&self) -> EndpointHealth {
+        let stats = self.stats.lock();
+        EndpointHealth {
+            url: self.url.clone(),
+            successes: stats.successes,
+            failures: stats.failures,
+            consecutive_failures: stats.consecutive_failures,
+        }
+    }
+
+    fn cooling_down_until(&self, now: Instant) -> Option<Instant> {
+        self.stats
+            .lock()
+            .cooling_down_until
+            .filter(|until| *until > now)
+    }
+}
+
+/// An ordered, non-empty list of endpoints shared by a client and all of its
+/// world views.
+pub(crate) struct Endpoints(Vec<Endpoint>);
+
+impl Endpoints {
+    pub(crate) fn new(urls: Vec<Url>) -> Self {
+        assert!(!urls.is_empty(), "at least one base URL is required");
+        Self(
+            urls.into_iter()
+                .map(|url| Endpoint {
+                    url,
+                    stats: Mutex::new(EndpointStats::default()),
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn urls(&self) -> Vec<Url> {
+        self.0.iter().map(|endpoint| endpoint.url.clone()).collect()
+    }
+
+    /// The index of the endpoint a new request should start on.
+    ///
+    /// That is the first endpoint in order that is not cooling down after a
+    /// failure, or the one whose cool-down ends first if they all are.
+    pub(crate) fn preferred(&self) -> usize {
+        self.first_available(0)
+    }
+
+    /// The index of the endpoint to fail over to after a request to
+    /// `current` failed.
+    ///
+    /// That is the next endpoint after `current`, cycling through the list,
+    /// that is not cooling down, or the one whose cool-down ends first if
+    /// they all are.
+    pub(crate) fn failover(&self, current: usize) -> usize {
+        self.first_available(current + 1)
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Endpoint {
+        &self.0[index]
+    }
+
+    fn first_available(&self, from: usize) -> usize {
+        let now = Instant::now();
+        let mut soonest: Option<(usize, Instant)> = None;
+        for offset in 0..self.0.len() {
+            let index = (from + offset) % self.0.len();
+            match self.0[index].cooling_down_until(now) {
+                None => return index,
+                Some(until) => {
+                    if soonest.is_none_or(|(_, best)| until < best) {
+                        soonest = Some((index, until));
+                    }
+                }
+            }
+        }
+        soonest.map(|(index, _)| index).unwrap_or(0)
+    }
+
+    pub(crate) fn health(&self) -> Vec<EndpointHealth> {
+        self.0.iter().map(Endpoint::health).collect()
+    }
+}
+
+/// Whether a failed request says the endpoint is unhealthy, rather than that
+/// the request itself was rejected. Connection errors, timeouts, server errors
+/// and rate limiting count; other client errors like a 404 don't.
+pub(crate) fn is_endpoint_failure(e: &reqwest::Error) -> bool {
+    match e.status() {
+        Some(status) => {
+            status.is_server_error()
+                || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        }
+        None => e.is_connect() || e.is_timeout() || e.is_request(),
+    }
+}
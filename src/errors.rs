@@ -2,6 +2,7 @@
 //!
 //! Errors that can occur when interacting with the EarthMC client.
 use thiserror::Error;
+use uuid::Uuid;
 
 /// Errors that can occur when interacting with the EarthMC client.
 #[derive(Error, Debug)]
@@ -15,6 +16,8 @@ pub enum Error {
         source: serde_json::Error,
         snippet: String,
     },
+    #[error("No entity found with ID {0}")]
+    NotFound(Uuid),
     #[error("Too many retries")]
     TooManyRetry(Vec<Error>),
 }
@@ -19,45 +19,91 @@
 //! cargo add earthmc
 //! ````
 //!
+//! ## Features
+//!
+//! The async client is behind the default `client` feature. Projects that
+//! only need to deserialize EarthMC JSON can turn it off, or depend on the
+//! `earthmc-types` crate directly, to avoid pulling in `reqwest` and `tokio`:
+//!
+//! ```toml
+//! [dependencies]
+//! earthmc = { version = "*", default-features = false }
+//! ```
+//!
+//! Responses can be compressed to reduce the size of large payloads such as the
+//! full list of players. Enable any of the following features to advertise
+//! support for an encoding with `Accept-Encoding` and decompress it
+//! transparently:
+//!
+//! - `gzip`
+//! - `brotli`
+//! - `zstd`
+//!
+//! Every timestamp is a [`Timestamp`](timestamp::Timestamp) of Unix milliseconds that converts to
+//! `SystemTime`. Enable the `chrono` or `time` feature to also convert it to
+//! the date-time types of those crates.
+//!
+//! Enable the `schemars` feature to derive `schemars::JsonSchema` for every
+//! model. The schemas follow the API's wire format, so they can be used to
+//! validate EarthMC JSON outside of Rust.
+//!
+//! Enable the `ts-rs` feature to derive `ts_rs::TS` for every model, which
+//! generates TypeScript definitions with the same field names as the JSON. See
+//! the `export_typescript` example.
+//!
 //! ## Usage
 //!
 //! ### Create a new client
 //!
 //! ```rust
+//! # #[cfg(feature = "client")] {
 //! # use earthmc::Client;
 //! #
 //! let client = Client::default();
+//! # }
 //! ```
 //!
 //! Finally, for more advanced configurations you can use a `ClientBuilder`:
 //!
 //! ```rust
+//! # #[cfg(feature = "client")] {
 //! # use earthmc::{ClientBuilder, world::World};
 //! #
 //! let client = ClientBuilder::default()
 //!     .world(World::Other("nostra".to_string()))
 //!     .build()
 //!     .unwrap();
+//! # }
 //! ```
 //!
 //! Detailed usage examples are in the `examples` directory.
+#[cfg(feature = "client")]
 pub mod client;
-pub mod discord_link;
+#[cfg(feature = "client")]
+pub mod concurrency;
+#[cfg(feature = "client")]
+pub mod endpoint;
+#[cfg(feature = "client")]
 pub mod errors;
-pub mod location;
-pub mod mystery_master;
-pub mod named_id;
-pub mod nation;
-pub mod permission;
-pub mod player;
-pub mod player_stats;
-pub mod quarter;
+#[cfg(feature = "client")]
+pub mod prepared;
+#[cfg(feature = "client")]
 pub mod query;
+#[cfg(feature = "client")]
+pub mod resolve;
+#[cfg(feature = "client")]
 pub mod retry_strategy;
-pub mod server;
-pub mod town;
-pub mod world;
-pub mod world_location;
+#[cfg(feature = "client")]
+pub mod stat_tracker;
+
+pub use earthmc_types::{
+    access, colour, discord_link, formatting, geometry, id, location,
+    mystery_master, named_id, nation, nearby, permission, player, player_stats,
+    position, quarter, quarter_check, server, spatial, stat_history, territory,
+    timestamp, town, world, world_location,
+};
 
+#[cfg(feature = "client")]
 pub use client::{Client, ClientBuilder};
+#[cfg(feature = "client")]
 pub use reqwest;
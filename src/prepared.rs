@@ -0,0 +1,165 @@
+//! # Prepared requests
+//!
+//! Defines the [`PreparedRequest`] struct, a request that has been built but
+//! not sent yet.
+//!
+//! Prepared requests are returned by the `prepare_*` methods of
+//! [`Client`](crate::client::Client). They show exactly what would be sent to
+//! the API, can be printed, serialized, turned into `curl` commands for bug
+//! reports, and executed later with
+//! [`Client::execute`](crate::client::Client::execute).
+use std::{fmt, marker::PhantomData};
+
+use reqwest::{Method, Url};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The HTTP method of a [`PreparedRequest`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpMethod {
+    Get,
+    Post,
+}
+
+impl HttpMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+        }
+    }
+}
+
+impl fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<HttpMethod> for Method {
+    fn from(method: HttpMethod) -> Self {
+        match method {
+            HttpMethod::Get => Method::GET,
+            HttpMethod::Post => Method::POST,
+        }
+    }
+}
+
+/// A request that has been built but not sent yet.
+///
+/// `T` is the type the response is deserialized into when the request is
+/// executed. Requests read back from their serialized form default to a raw
+/// [`serde_json::Value`] response.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(bound = "")]
+pub struct PreparedRequest<T = serde_json::Value> {
+    /// The HTTP method.
+    pub method: HttpMethod,
+    /// The fully resolved URL, including the world.
+    #[serde(
+        serialize_with = "serialize_url",
+        deserialize_with = "deserialize_url"
+    )]
+    pub url: Url,
+    /// The JSON body, for POST requests.
+    pub body: Option<serde_json::Value>,
+    #[serde(skip)]
+    response: PhantomData<fn() -> T>,
+}
+
+impl<T> PreparedRequest<T> {
+    pub(crate) fn new(
+        method: HttpMethod,
+        url: Url,
+        body: Option<serde_json::Value>,
+    ) -> Self {
+        Self {
+            method,
+            url,
+            body,
+            response: PhantomData,
+        }
+    }
+
+    /// Changes the type the response is deserialized into.
+    pub fn with_response<U>(self) -> PreparedRequest<U> {
+        PreparedRequest::new(self.method, self.url, self.body)
+    }
+
+    /// Renders the request as a `curl` command line.
+    ///
+    /// ```rust
+    /// # use earthmc::{Client, query::SimpleQueryBuilder};
+    /// #
+    /// let client = Client::default();
+    /// let query = SimpleQueryBuilder::default().insert("London").build().unwrap();
+    /// let request = client.prepare_towns(query);
+    ///
+    /// assert_eq!(
+    ///     request.to_curl(),
+    ///     "curl -X POST 'https://api.earthmc.net/v3/aurora/towns' \
+    ///      -H 'Content-Type: application/json' \
+    ///      --data '{\"query\":[\"London\"]}'"
+    /// );
+    /// ```
+    pub fn to_curl(&self) -> String {
+        let mut command = format!(
+            "curl -X {} {}",
+            self.method,
+            shell_quote(self.url.as_str())
+        );
+        if let Some(body) = &self.body {
+            command.push_str(" -H 'Content-Type: application/json' --data ");
+            command.push_str(&shell_quote(&body.to_string()));
+        }
+        command
+    }
+}
+
+impl<T> Clone for PreparedRequest<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.method, self.url.clone(), self.body.clone())
+    }
+}
+
+impl<T> PartialEq for PreparedRequest<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.url == other.url
+            && self.body == other.body
+    }
+}
+
+/// Prints the method and URL, followed by the pretty-printed body if there is
+/// one.
+impl<T> fmt::Display for PreparedRequest<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)?;
+        if let Some(body) = &self.body {
+            let pretty =
+                serde_json::to_string_pretty(body).map_err(|_| fmt::Error)?;
+            write!(f, "\n{pretty}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps `value` in single quotes for POSIX shells.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+fn serialize_url<S>(url: &Url, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(url.as_str())
+}
+
+fn deserialize_url<'de, D>(deserializer: D) -> Result<Url, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    raw.parse().map_err(serde::de::Error::custom)
+}
@@ -1,9 +1,24 @@
 //! # Query
 //!
 //! Defines structs and enums used for querying specific information from the API.
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
 use derive_builder::Builder;
 use serde::Serialize;
-use uuid::Uuid;
+
+use crate::{
+    id::{Id, PlayerId, QuarterId},
+    named_id::NamedId,
+    nation::Nation,
+    nearby::{DistanceTo, NearbySearch, NearbySearchTarget},
+    player::Player,
+    position::BlockPosXZ,
+    town::Town,
+};
 
 #[derive(Serialize)]
 pub(crate) struct Query<D>
@@ -19,49 +34,160 @@ impl<D: Serialize + Sized> From<D> for Query<D> {
     }
 }
 
-/// An enum that can hold either a `String` or a [`Uuid`].
-#[derive(Serialize, Clone)]
-#[serde(untagged)]
-pub enum StrOrUuid {
+/// An enum that can hold either a `String` or the [`Id`] of an entity of kind
+/// `K`.
+#[derive(Serialize)]
+#[serde(untagged, bound = "")]
+pub enum StrOrId<K> {
     Str(String),
-    Uid(Uuid),
+    Id(Id<K>),
+}
+
+// implemented by hand as deriving would require `K` itself to implement
+// these traits, while it is only a marker
+
+impl<K> Clone for StrOrId<K> {
+    fn clone(&self) -> Self {
+        match self {
+            StrOrId::Str(s) => StrOrId::Str(s.clone()),
+            StrOrId::Id(id) => StrOrId::Id(*id),
+        }
+    }
+}
+
+impl<K> fmt::Debug for StrOrId<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrOrId::Str(s) => f.debug_tuple("Str").field(s).finish(),
+            StrOrId::Id(id) => f.debug_tuple("Id").field(id).finish(),
+        }
+    }
 }
 
-impl From<&str> for StrOrUuid {
+impl<K> PartialEq for StrOrId<K> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (StrOrId::Str(a), StrOrId::Str(b)) => a == b,
+            (StrOrId::Id(a), StrOrId::Id(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<K> Eq for StrOrId<K> {}
+
+impl<K> Hash for StrOrId<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            StrOrId::Str(s) => (0_u8, s).hash(state),
+            StrOrId::Id(id) => (1_u8, id).hash(state),
+        }
+    }
+}
+
+impl<K> From<&str> for StrOrId<K> {
     fn from(s: &str) -> Self {
-        StrOrUuid::Str(s.to_string())
+        StrOrId::Str(s.to_string())
     }
 }
 
-impl From<String> for StrOrUuid {
+impl<K> From<String> for StrOrId<K> {
     fn from(s: String) -> Self {
-        StrOrUuid::Str(s)
+        StrOrId::Str(s)
     }
 }
 
-impl From<Uuid> for StrOrUuid {
-    fn from(u: Uuid) -> Self {
-        StrOrUuid::Uid(u)
+impl<K> From<Id<K>> for StrOrId<K> {
+    fn from(id: Id<K>) -> Self {
+        StrOrId::Id(id)
     }
 }
 
-/// An API query that looks up by either UUIDs or names.
+impl<K> From<&NamedId<K>> for StrOrId<K> {
+    fn from(named: &NamedId<K>) -> Self {
+        StrOrId::Id(named.uuid)
+    }
+}
+
+/// An API query that looks up entities of kind `K` by either IDs or names.
+///
+/// The kind is usually inferred from the client method the query is passed
+/// to, and only IDs of that kind can be inserted:
+///
+/// ```rust,compile_fail
+/// # use earthmc::{id::NationId, query::{SimpleQuery, SimpleQueryBuilder}};
+/// # use earthmc::town::Town;
+/// let query: SimpleQuery<Town> = SimpleQueryBuilder::default()
+///     .insert(NationId::new(uuid::Uuid::nil()))
+///     .build()
+///     .unwrap();
+/// ```
 #[derive(Serialize, Builder)]
-#[serde(transparent)]
+#[serde(transparent, bound = "")]
 #[builder(pattern = "owned")]
-pub struct SimpleQuery {
+pub struct SimpleQuery<K> {
     #[builder(default)]
-    values: Vec<StrOrUuid>,
+    values: Vec<StrOrId<K>>,
+    #[serde(skip)]
+    #[builder(setter(skip))]
+    kind: PhantomData<fn() -> K>,
+}
+
+/// A query for [`Client::towns`](crate::client::Client::towns).
+pub type TownQuery = SimpleQuery<Town>;
+/// A query for [`Client::nations`](crate::client::Client::nations).
+pub type NationQuery = SimpleQuery<Nation>;
+/// A query for [`Client::players`](crate::client::Client::players).
+pub type PlayerQuery = SimpleQuery<Player>;
+
+impl<K> Clone for SimpleQuery<K> {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> fmt::Debug for SimpleQuery<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimpleQuery")
+            .field("values", &self.values)
+            .finish()
+    }
 }
 
-impl SimpleQueryBuilder {
-    pub fn insert<T: Into<StrOrUuid>>(mut self, single: T) -> Self {
+impl<K> PartialEq for SimpleQuery<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+impl<K> Eq for SimpleQuery<K> {}
+
+impl<K> Hash for SimpleQuery<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.values.hash(state);
+    }
+}
+
+impl<K> Default for SimpleQuery<K> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> SimpleQueryBuilder<K> {
+    pub fn insert<T: Into<StrOrId<K>>>(mut self, single: T) -> Self {
         self.values.get_or_insert_with(Vec::new).push(single.into());
         self
     }
 }
 
-#[derive(Serialize, Builder)]
+#[derive(Serialize, Builder, Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[serde(transparent)]
 #[builder(pattern = "owned")]
 pub struct NearbyQuery {
@@ -69,7 +195,7 @@ pub struct NearbyQuery {
     values: Vec<NearbyQueryItem>,
 }
 
-#[derive(Debug, Serialize, Builder)]
+#[derive(Serialize, Builder, Debug, Clone, PartialEq, Eq, Hash)]
 #[builder(pattern = "owned")]
 pub struct NearbyQueryItem {
     pub target_type: NearbyTargetType,
@@ -78,35 +204,59 @@ pub struct NearbyQueryItem {
     pub radius: i32,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum NearbyTargetType {
     Town,
     Coordinate,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum NearbySearchType {
     Town,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(untagged)]
 pub enum NearbyTarget {
     Town(String),
-    Coordinates([i32; 2]),
+    Coordinates(BlockPosXZ),
 }
 
-#[derive(Serialize, Builder)]
+/// Converts the item into a search the offline
+/// [`Nearby`](crate::nearby::Nearby) engine can run, measuring to home blocks
+/// like the endpoint. The target decides whether it searches around a town or
+/// a coordinate.
+impl From<&NearbyQueryItem> for NearbySearch {
+    fn from(item: &NearbyQueryItem) -> Self {
+        // the endpoint only searches for towns so far
+        let NearbySearchType::Town = item.search_type;
+        Self {
+            target: match &item.target {
+                NearbyTarget::Town(name) => {
+                    NearbySearchTarget::Town(name.clone())
+                }
+                NearbyTarget::Coordinates(block) => {
+                    NearbySearchTarget::Coordinates(*block)
+                }
+            },
+            radius: item.radius as f64,
+            to: DistanceTo::HomeBlock,
+        }
+    }
+}
+
+/// An API query that looks up Quarters by their IDs.
+#[derive(Serialize, Builder, Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[serde(transparent)]
 #[builder(pattern = "owned")]
 pub struct UuidQuery {
     #[builder(default, setter(each = "insert"))]
-    values: Vec<Uuid>,
+    values: Vec<QuarterId>,
 }
 
-#[derive(Serialize, Builder)]
+#[derive(Serialize, Builder, Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[serde(transparent)]
 #[builder(pattern = "owned")]
 pub struct DiscordQuery {
@@ -114,17 +264,17 @@ pub struct DiscordQuery {
     values: Vec<DiscordQueryItem>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase", tag = "type")]
 pub enum DiscordQueryItem {
-    Minecraft { target: Uuid },
+    Minecraft { target: PlayerId },
     Discord { target: String },
 }
 
-#[derive(Serialize, Builder)]
+#[derive(Serialize, Builder, Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[serde(transparent)]
 #[builder(pattern = "owned")]
 pub struct LocationQuery {
     #[builder(default, setter(each = "insert"))]
-    values: Vec<[i32; 2]>,
+    values: Vec<BlockPosXZ>,
 }
@@ -0,0 +1,386 @@
+//! # Resolve
+//!
+//! Expands [`NamedId`] references into the full entities they point to.
+//!
+//! A single reference is expanded with [`Client::resolve`], a list of
+//! references of the same kind with [`Client::resolve_all`], and references of
+//! any kinds with a [`Resolver`], which deduplicates the IDs and fetches each
+//! kind with as few requests as possible:
+//!
+//! ```rust,no_run
+//! # use earthmc::{Client, query::SimpleQueryBuilder};
+//! # async fn run() -> Result<(), earthmc::errors::Error> {
+//! let client = Client::default();
+//! let query = SimpleQueryBuilder::default().insert("London").build().unwrap();
+//! let town = client.towns(query).await?.remove(0);
+//!
+//! let mayor = client.resolve(&town.mayor).await?;
+//! let nation = client.resolve(&town.nation).await?;
+//!
+//! let resolved = client
+//!     .resolver()
+//!     .insert_all(&town.residents)
+//!     .insert_all(&town.quarters)
+//!     .resolve()
+//!     .await?;
+//! for resident in &town.residents {
+//!     if let Some(player) = resolved.get(resident) {
+//!         println!("{} has {}G", player.name, player.stats.balance);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Client::resolve`]: crate::client::Client::resolve
+//! [`Client::resolve_all`]: crate::client::Client::resolve_all
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+};
+
+use crate::{
+    client::Client,
+    errors::Error,
+    id::Id,
+    named_id::{NamedId, NamedIdOpt},
+    nation::Nation,
+    player::Player,
+    quarter::Quarter,
+    town::Town,
+};
+
+/// An entity that can be fetched by its [`Id`].
+pub trait Resolvable: Sized {
+    /// The entity's own ID.
+    fn id(&self) -> Id<Self>;
+
+    /// Fetches the entities with the given IDs. Entities that no longer exist
+    /// are omitted.
+    fn fetch(
+        client: &Client,
+        ids: Vec<Id<Self>>,
+    ) -> impl Future<Output = Result<Vec<Self>, Error>> + Send;
+
+    /// The IDs of this kind queued in `resolver`.
+    fn queued(resolver: &mut Resolver) -> &mut HashSet<Id<Self>>;
+
+    /// The entities of this kind in `resolved`.
+    fn found(resolved: &Resolved) -> &HashMap<Id<Self>, Self>;
+}
+
+/// A reference to a [`Resolvable`] entity, such as a [`NamedId`].
+pub trait Reference {
+    /// The kind of entity referred to.
+    type Target: Resolvable;
+    /// What resolving the reference returns: the entity for references that
+    /// are always set, or an [`Option`] for those that may be empty.
+    type Output;
+
+    /// The ID referred to, if any.
+    fn target_id(&self) -> Option<Id<Self::Target>>;
+
+    /// Turns the entity found for this reference, if any, into the output.
+    fn output(
+        &self,
+        found: Option<Self::Target>,
+    ) -> Result<Self::Output, Error>;
+}
+
+impl<K: Resolvable> Reference for Id<K> {
+    type Target = K;
+    type Output = K;
+
+    fn target_id(&self) -> Option<Id<K>> {
+        Some(*self)
+    }
+
+    fn output(&self, found: Option<K>) -> Result<K, Error> {
+        found.ok_or(Error::NotFound(self.uuid()))
+    }
+}
+
+impl<K: Resolvable> Reference for NamedId<K> {
+    type Target = K;
+    type Output = K;
+
+    fn target_id(&self) -> Option<Id<K>> {
+        Some(self.uuid)
+    }
+
+    fn output(&self, found: Option<K>) -> Result<K, Error> {
+        found.ok_or(Error::NotFound(self.uuid.uuid()))
+    }
+}
+
+impl<K: Resolvable> Reference for NamedIdOpt<K> {
+    type Target = K;
+    type Output = Option<K>;
+
+    fn target_id(&self) -> Option<Id<K>> {
+        self.uuid
+    }
+
+    fn output(&self, found: Option<K>) -> Result<Option<K>, Error> {
+        Ok(found)
+    }
+}
+
+impl Client {
+    /// Fetches the entity a reference points to.
+    ///
+    /// Fails with [`Error::NotFound`] if a required reference points to an
+    /// entity that no longer exists. Optional references, such as a town's
+    /// nation, resolve to [`None`] instead.
+    pub async fn resolve<R: Reference>(
+        &self,
+        reference: &R,
+    ) -> Result<R::Output, Error> {
+        let found = match reference.target_id() {
+            Some(id) => R::Target::fetch(self, vec![id])
+                .await?
+                .into_iter()
+                .find(|entity| entity.id() == id),
+            None => None,
+        };
+        reference.output(found)
+    }
+
+    /// Fetches the entities a list of references points to.
+    ///
+    /// Duplicate references are fetched once and returned once, in the order
+    /// they first appear. Entities that no longer exist are omitted.
+    pub async fn resolve_all<'a, R, I>(
+        &self,
+        references: I,
+    ) -> Result<Vec<R::Target>, Error>
+    where
+        R: Reference + 'a,
+        I: IntoIterator<Item = &'a R>,
+    {
+        let mut seen = HashSet::new();
+        let ids: Vec<_> = references
+            .into_iter()
+            .filter_map(Reference::target_id)
+            .filter(|id| seen.insert(*id))
+            .collect();
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut found: HashMap<_, _> = R::Target::fetch(self, ids.clone())
+            .await?
+            .into_iter()
+            .map(|entity| (entity.id(), entity))
+            .collect();
+        Ok(ids.iter().filter_map(|id| found.remove(id)).collect())
+    }
+
+    /// Starts a batch of references of any kind to resolve together.
+    pub fn resolver(&self) -> Resolver {
+        Resolver {
+            client: self.clone(),
+            towns: HashSet::new(),
+            nations: HashSet::new(),
+            players: HashSet::new(),
+            quarters: HashSet::new(),
+        }
+    }
+}
+
+/// A batch of references to resolve with as few requests as possible.
+///
+/// Created with [`Client::resolver`].
+pub struct Resolver {
+    client: Client,
+    towns: HashSet<Id<Town>>,
+    nations: HashSet<Id<Nation>>,
+    players: HashSet<Id<Player>>,
+    quarters: HashSet<Id<Quarter>>,
+}
+
+impl Resolver {
+    /// Queues a reference.
+    pub fn insert<R: Reference>(mut self, reference: &R) -> Self {
+        if let Some(id) = reference.target_id() {
+            R::Target::queued(&mut self).insert(id);
+        }
+        self
+    }
+
+    /// Queues a list of references.
+    pub fn insert_all<'a, R, I>(mut self, references: I) -> Self
+    where
+        R: Reference + 'a,
+        I: IntoIterator<Item = &'a R>,
+    {
+        for reference in references {
+            self = self.insert(reference);
+        }
+        self
+    }
+
+    /// Fetches every queued entity, each kind concurrently.
+    pub async fn resolve(self) -> Result<Resolved, Error> {
+        let client = &self.client;
+        let (towns, nations, players, quarters) = futures_util::try_join!(
+            fetch_map(client, self.towns),
+            fetch_map(client, self.nations),
+            fetch_map(client, self.players),
+            fetch_map(client, self.quarters),
+        )?;
+        Ok(Resolved {
+            towns,
+            nations,
+            players,
+            quarters,
+        })
+    }
+}
+
+async fn fetch_map<K: Resolvable>(
+    client: &Client,
+    ids: HashSet<Id<K>>,
+) -> Result<HashMap<Id<K>, K>, Error> {
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let found = K::fetch(client, ids.into_iter().collect()).await?;
+    Ok(found
+        .into_iter()
+        .map(|entity| (entity.id(), entity))
+        .collect())
+}
+
+/// The entities fetched by a [`Resolver`].
+#[derive(Debug, Clone, Default)]
+pub struct Resolved {
+    towns: HashMap<Id<Town>, Town>,
+    nations: HashMap<Id<Nation>, Nation>,
+    players: HashMap<Id<Player>, Player>,
+    quarters: HashMap<Id<Quarter>, Quarter>,
+}
+
+impl Resolved {
+    /// The entity a reference points to, if it was queued and still exists.
+    pub fn get<R: Reference>(&self, reference: &R) -> Option<&R::Target> {
+        R::Target::found(self).get(&reference.target_id()?)
+    }
+
+    /// The entities a list of references points to, skipping those that were
+    /// not found.
+    pub fn get_all<'r, R, I>(&self, references: I) -> Vec<&R::Target>
+    where
+        R: Reference + 'r,
+        I: IntoIterator<Item = &'r R>,
+    {
+        references
+            .into_iter()
+            .filter_map(|reference| self.get(reference))
+            .collect()
+    }
+
+    /// Every fetched town.
+    pub fn towns(&self) -> &HashMap<Id<Town>, Town> {
+        &self.towns
+    }
+
+    /// Every fetched nation.
+    pub fn nations(&self) -> &HashMap<Id<Nation>, Nation> {
+        &self.nations
+    }
+
+    /// Every fetched player.
+    pub fn players(&self) -> &HashMap<Id<Player>, Player> {
+        &self.players
+    }
+
+    /// Every fetched Quarter.
+    pub fn quarters(&self) -> &HashMap<Id<Quarter>, Quarter> {
+        &self.quarters
+    }
+}
+
+impl Resolvable for Town {
+    fn id(&self) -> Id<Self> {
+        self.uuid
+    }
+
+    fn fetch(
+        client: &Client,
+        ids: Vec<Id<Self>>,
+    ) -> impl Future<Output = Result<Vec<Self>, Error>> + Send {
+        client.towns_bulk(ids)
+    }
+
+    fn queued(resolver: &mut Resolver) -> &mut HashSet<Id<Self>> {
+        &mut resolver.towns
+    }
+
+    fn found(resolved: &Resolved) -> &HashMap<Id<Self>, Self> {
+        &resolved.towns
+    }
+}
+
+impl Resolvable for Nation {
+    fn id(&self) -> Id<Self> {
+        self.uuid
+    }
+
+    fn fetch(
+        client: &Client,
+        ids: Vec<Id<Self>>,
+    ) -> impl Future<Output = Result<Vec<Self>, Error>> + Send {
+        client.nations_bulk(ids)
+    }
+
+    fn queued(resolver: &mut Resolver) -> &mut HashSet<Id<Self>> {
+        &mut resolver.nations
+    }
+
+    fn found(resolved: &Resolved) -> &HashMap<Id<Self>, Self> {
+        &resolved.nations
+    }
+}
+
+impl Resolvable for Player {
+    fn id(&self) -> Id<Self> {
+        self.uuid
+    }
+
+    fn fetch(
+        client: &Client,
+        ids: Vec<Id<Self>>,
+    ) -> impl Future<Output = Result<Vec<Self>, Error>> + Send {
+        client.players_bulk(ids)
+    }
+
+    fn queued(resolver: &mut Resolver) -> &mut HashSet<Id<Self>> {
+        &mut resolver.players
+    }
+
+    fn found(resolved: &Resolved) -> &HashMap<Id<Self>, Self> {
+        &resolved.players
+    }
+}
+
+impl Resolvable for Quarter {
+    fn id(&self) -> Id<Self> {
+        self.uuid
+    }
+
+    fn fetch(
+        client: &Client,
+        ids: Vec<Id<Self>>,
+    ) -> impl Future<Output = Result<Vec<Self>, Error>> + Send {
+        client.quarters_bulk(ids)
+    }
+
+    fn queued(resolver: &mut Resolver) -> &mut HashSet<Id<Self>> {
+        &mut resolver.quarters
+    }
+
+    fn found(resolved: &Resolved) -> &HashMap<Id<Self>, Self> {
+        &resolved.quarters
+    }
+}
@@ -0,0 +1,112 @@
+//! # Stat Tracker
+//!
+//! Defines [`StatTracker`], which samples [`Client::player_stats`] on an
+//! interval and keeps the readings in a [`StatHistory`].
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), earthmc::errors::Error> {
+//! use std::time::Duration;
+//!
+//! use earthmc::{
+//!     Client, player_stats::StatKind, stat_tracker::StatTracker,
+//! };
+//!
+//! // one sample every 5 minutes, keeping a day of history
+//! let tracker = StatTracker::new(
+//!     Client::default(),
+//!     Duration::from_secs(5 * 60),
+//!     24 * 12 + 1,
+//! );
+//! tracker.spawn();
+//!
+//! // later on...
+//! if let Some(diff) = tracker.diff_over(Duration::from_secs(24 * 60 * 60)) {
+//!     let walked = diff.delta(StatKind::WalkOneCm).unwrap_or_default();
+//!     println!(
+//!         "Walked server-wide in the last 24h: {}",
+//!         StatKind::WalkOneCm.format_value(walked)
+//!     );
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Client::player_stats`]: crate::client::Client::player_stats
+use std::{sync::Arc, time::Duration};
+
+use parking_lot::Mutex;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+
+use crate::{
+    Client,
+    errors::Error,
+    stat_history::{StatDiff, StatHistory, StatSample},
+};
+
+/// Samples the server-wide player stats on an interval.
+///
+/// Clones share the same history.
+#[derive(Clone)]
+pub struct StatTracker {
+    client: Client,
+    interval: Duration,
+    history: Arc<Mutex<StatHistory>>,
+}
+
+impl StatTracker {
+    /// Creates a tracker that samples every `interval` and keeps the latest
+    /// `capacity` samples.
+    pub fn new(client: Client, interval: Duration, capacity: usize) -> Self {
+        Self {
+            client,
+            interval,
+            history: Arc::new(Mutex::new(StatHistory::new(capacity))),
+        }
+    }
+
+    /// The time between samples taken by [`StatTracker::spawn`].
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Fetches the player stats once and adds them to the history.
+    pub async fn sample(&self) -> Result<StatSample, Error> {
+        let stats = self.client.player_stats().await?;
+        let sample = StatSample::now(stats);
+        self.history.lock().push(sample.clone());
+        Ok(sample)
+    }
+
+    /// Spawns a task that takes a sample right away and then once every
+    /// interval, until it is aborted.
+    ///
+    /// Failed samples are skipped; the client's retry strategy already
+    /// retries transient failures.
+    pub fn spawn(&self) -> JoinHandle<()> {
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(tracker.interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let _ = tracker.sample().await;
+            }
+        })
+    }
+
+    /// A copy of the samples taken so far.
+    pub fn history(&self) -> StatHistory {
+        self.history.lock().clone()
+    }
+
+    /// The latest sample, if any.
+    pub fn latest(&self) -> Option<StatSample> {
+        self.history.lock().latest().cloned()
+    }
+
+    /// The change over roughly the last `window`. See
+    /// [`StatHistory::diff_over`].
+    pub fn diff_over(&self, window: Duration) -> Option<StatDiff> {
+        self.history.lock().diff_over(window)
+    }
+}
@@ -0,0 +1,189 @@
+//! A minimal HTTP/1.1 server for exercising the client against local
+//! responses.
+#![allow(dead_code)]
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use earthmc::{reqwest::Url, retry_strategy::RetryStrategy};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// Retries up to three times without waiting.
+pub struct RetryImmediately;
+
+impl RetryStrategy for RetryImmediately {
+    fn should_retry_after(&mut self, attempt: usize) -> Option<Duration> {
+        (attempt < 3).then_some(Duration::ZERO)
+    }
+}
+
+/// A request received by a [`MockServer`].
+pub struct MockRequest {
+    /// The request line, e.g. `POST /v3/aurora/towns HTTP/1.1`.
+    pub line: String,
+    /// The raw header lines.
+    pub headers: Vec<String>,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// Returns the value of the header called `name`, if present.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+}
+
+/// A response returned by a [`MockServer`] handler.
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// How long to wait before responding.
+    pub delay: Duration,
+}
+
+impl MockResponse {
+    pub fn json(body: &str) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("Content-Type".into(), "application/json".into())],
+            body: body.as_bytes().to_vec(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    pub fn status(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Responds after `delay` instead of straight away.
+    pub fn delayed(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+}
+
+pub struct MockServer {
+    /// The base URL of the server, ending in `/v3/`.
+    pub url: Url,
+    requests: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+    /// The number of requests served so far.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+/// Starts a server on a random local port that answers every request with
+/// `handler`.
+pub async fn serve<F>(handler: F) -> MockServer
+where
+    F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let requests = Arc::new(AtomicUsize::new(0));
+    let handler = Arc::new(handler);
+
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                return;
+            };
+            let handler = handler.clone();
+            let counter = counter.clone();
+            tokio::spawn(async move {
+                let Some(request) = read_request(&mut stream).await else {
+                    return;
+                };
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = handler(&request);
+                tokio::time::sleep(response.delay).await;
+
+                let mut head = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    response.status,
+                    response.body.len()
+                );
+                for (key, value) in &response.headers {
+                    head.push_str(&format!("{key}: {value}\r\n"));
+                }
+                head.push_str("\r\n");
+                let _ = stream.write_all(head.as_bytes()).await;
+                let _ = stream.write_all(&response.body).await;
+                let _ = stream.shutdown().await;
+            });
+        }
+    });
+
+    MockServer {
+        url: format!("http://{address}/v3/").parse().unwrap(),
+        requests,
+    }
+}
+
+/// A base URL nothing is listening on.
+pub async fn unreachable_url() -> Url {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    drop(listener);
+    format!("http://{address}/v3/").parse().unwrap()
+}
+
+async fn read_request(
+    stream: &mut tokio::net::TcpStream,
+) -> Option<MockRequest> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0_u8; 4096];
+    let head_end = loop {
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(index) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break index;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).to_string();
+    let mut lines = head.split("\r\n").map(str::to_owned);
+    let line = lines.next()?;
+    let headers: Vec<String> = lines.collect();
+
+    let mut request = MockRequest {
+        line,
+        headers,
+        body: buffer[head_end + 4..].to_vec(),
+    };
+    let length = request
+        .header("content-length")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    while request.body.len() < length {
+        let read = stream.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        request.body.extend_from_slice(&chunk[..read]);
+    }
+    Some(request)
+}
@@ -0,0 +1,88 @@
+use earthmc::{
+    access::{DecidedBy, TownAccess},
+    nation::Nation,
+    permission::{TownyAction, TownyPermissionLevel, TownyPermissionSet},
+    player::Player,
+    town::Town,
+};
+
+/// London's town, a nation whose uuid is set to London's nation, and a
+/// player whose town and nation the tests change.
+fn fixtures() -> (Town, Nation, Player) {
+    let mut town: Vec<Town> =
+        serde_json::from_str(include_str!("inputs/town.json")).unwrap();
+    let mut nation: Vec<Nation> =
+        serde_json::from_str(include_str!("inputs/nation.json")).unwrap();
+    let mut player: Vec<Player> =
+        serde_json::from_str(include_str!("inputs/player.json")).unwrap();
+    let (mut town, mut nation, player) =
+        (town.remove(0), nation.remove(0), player.remove(0));
+
+    // give the nation London's nation uuid, so London counts as a member
+    nation.uuid = town.nation.uuid.unwrap();
+    town.perms.build = TownyPermissionSet {
+        resident: true,
+        nation: true,
+        ally: false,
+        outsider: false,
+    };
+    (town, nation, player)
+}
+
+#[test]
+fn test_relationship_levels() {
+    let (town, nation, mut player) = fixtures();
+    let united_kingdom = town.nation.uuid.unwrap();
+    let netherlands = nation.allies[0].uuid;
+
+    let level = |player: &Player| {
+        TownAccess::new(player, &town, Some(&nation))
+            .relationship()
+            .level
+    };
+
+    // Fix's town and nation are unrelated to London
+    assert_eq!(level(&player), TownyPermissionLevel::Outsider);
+
+    player.nation.uuid = Some(netherlands);
+    assert_eq!(level(&player), TownyPermissionLevel::Ally);
+    // without the town's nation allies can't be told apart from outsiders
+    let access = TownAccess::new(&player, &town, None);
+    assert_eq!(access.relationship().level, TownyPermissionLevel::Outsider);
+
+    player.nation.uuid = Some(united_kingdom);
+    assert_eq!(level(&player), TownyPermissionLevel::Nation);
+
+    player.town.uuid = Some(town.uuid);
+    assert_eq!(level(&player), TownyPermissionLevel::Resident);
+}
+
+#[test]
+fn test_decisions() {
+    let (town, nation, mut player) = fixtures();
+
+    let access = TownAccess::new(&player, &town, Some(&nation));
+    assert!(!access.can(TownyAction::Build));
+
+    player.nation.uuid = town.nation.uuid;
+    let access = TownAccess::new(&player, &town, Some(&nation));
+    let decision = access.check(TownyAction::Build);
+    assert!(decision.allowed);
+    assert_eq!(
+        decision.decided_by,
+        DecidedBy::Permission(TownyPermissionLevel::Nation)
+    );
+    assert!(!access.can(TownyAction::Destroy));
+
+    // trusted players may do anything, outlaws are only flagged
+    player.uuid = town.trusted[0].uuid;
+    let access = TownAccess::new(&player, &town, Some(&nation));
+    assert!(access.check_all().iter().all(|decision| {
+        decision.allowed && decision.decided_by == DecidedBy::Trusted
+    }));
+
+    player.uuid = town.outlaws[0].uuid;
+    let access = TownAccess::new(&player, &town, Some(&nation));
+    assert!(access.relationship().is_outlaw);
+    assert!(access.can(TownyAction::Build));
+}
@@ -0,0 +1,69 @@
+use earthmc::colour::{ParseColourError, Rgb, Rgba};
+
+#[test]
+fn test_rgb_hex() {
+    let colour: Rgb = "0000FF".parse().unwrap();
+    assert_eq!(colour, Rgb::new(0, 0, 255));
+    assert_eq!("#00f".parse::<Rgb>().unwrap(), colour);
+    assert_eq!("#0000ff".parse::<Rgb>().unwrap(), colour);
+
+    assert_eq!(colour.to_hex(), "0000FF");
+    assert_eq!(colour.to_string(), "#0000FF");
+    assert_eq!(colour.to_css(), "#0000FF");
+
+    assert_eq!(
+        "00FF".parse::<Rgb>(),
+        Err(ParseColourError::InvalidLength {
+            expected: "3 or 6",
+            found: 4
+        })
+    );
+    assert_eq!(
+        "00FFGG".parse::<Rgb>(),
+        Err(ParseColourError::InvalidDigit('G'))
+    );
+}
+
+#[test]
+fn test_rgba_hex() {
+    let colour: Rgba = "#3FB4FF80".parse().unwrap();
+    assert_eq!(colour, Rgba::new(63, 180, 255, 128));
+    assert_eq!(colour.to_string(), "#3FB4FF80");
+    assert_eq!(colour.to_css(), "rgba(63, 180, 255, 0.502)");
+    assert_eq!(
+        "3FB4FF".parse::<Rgba>().unwrap(),
+        Rgba::new(63, 180, 255, 255)
+    );
+    assert_eq!(Rgba::new(0, 0, 0, 255).to_css(), "rgba(0, 0, 0, 1)");
+}
+
+#[test]
+fn test_packed() {
+    let rgb = Rgb::new(0x12, 0x34, 0x56);
+    assert_eq!(rgb.to_u32(), 0x123456);
+    assert_eq!(Rgb::from_u32(0xFF123456), rgb);
+
+    let rgba = Rgba::new(0x12, 0x34, 0x56, 0x78);
+    assert_eq!(rgba.to_u32_rgba(), 0x12345678);
+    assert_eq!(rgba.to_u32_argb(), 0x78123456);
+    assert_eq!(Rgba::from_u32_rgba(0x12345678), rgba);
+    assert_eq!(Rgba::from_u32_argb(0x78123456), rgba);
+    assert_eq!(rgba.rgb(), rgb);
+}
+
+#[test]
+fn test_ansi() {
+    let colour = Rgb::new(63, 180, 255);
+    assert_eq!(colour.ansi_fg(), "\x1b[38;2;63;180;255m");
+    assert_eq!(colour.ansi_bg(), "\x1b[48;2;63;180;255m");
+    assert_eq!(colour.with_alpha(0).ansi_fg(), colour.ansi_fg());
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let rgb: Rgb = serde_json::from_str("\"0000FF\"").unwrap();
+    assert_eq!(serde_json::to_string(&rgb).unwrap(), "\"0000FF\"");
+
+    let rgba: Rgba = serde_json::from_str("[63, 180, 255, 255]").unwrap();
+    assert_eq!(serde_json::to_string(&rgba).unwrap(), "[63,180,255,255]");
+}
@@ -0,0 +1,82 @@
+#![cfg(any(feature = "gzip", feature = "brotli", feature = "zstd"))]
+mod common;
+
+use std::io::Write;
+
+use earthmc::{ClientBuilder, query::SimpleQueryBuilder, town::Town};
+
+/// Serves the town fixture compressed with `encoding`, refusing clients that
+/// do not advertise support for it.
+async fn fetch_compressed(
+    encoding: &'static str,
+    compress: fn(&[u8]) -> Vec<u8>,
+) -> Vec<Town> {
+    let server = common::serve(move |request| {
+        let accepted = request.header("accept-encoding").unwrap_or_default();
+        if !accepted.split(',').any(|value| value.trim() == encoding) {
+            return common::MockResponse::status(406);
+        }
+        let mut response =
+            common::MockResponse::json(include_str!("inputs/town.json"));
+        response.body = compress(&response.body);
+        response
+            .headers
+            .push(("Content-Encoding".into(), encoding.into()));
+        response
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_url(server.url.clone())
+        .build()
+        .unwrap();
+    let query = SimpleQueryBuilder::default()
+        .insert("London")
+        .build()
+        .unwrap();
+    client.towns(query).await.unwrap()
+}
+
+fn expected() -> Vec<Town> {
+    serde_json::from_str(include_str!("inputs/town.json")).unwrap()
+}
+
+#[cfg(feature = "gzip")]
+#[tokio::test]
+async fn test_compression_gzip() {
+    let towns = fetch_compressed("gzip", |body| {
+        let mut encoder = flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::default(),
+        );
+        encoder.write_all(body).unwrap();
+        encoder.finish().unwrap()
+    })
+    .await;
+    assert_eq!(towns, expected());
+}
+
+#[cfg(feature = "brotli")]
+#[tokio::test]
+async fn test_compression_brotli() {
+    let towns = fetch_compressed("br", |body| {
+        let mut compressed = Vec::new();
+        {
+            let mut writer =
+                brotli::CompressorWriter::new(&mut compressed, 4096, 5, 22);
+            writer.write_all(body).unwrap();
+        }
+        compressed
+    })
+    .await;
+    assert_eq!(towns, expected());
+}
+
+#[cfg(feature = "zstd")]
+#[tokio::test]
+async fn test_compression_zstd() {
+    let towns =
+        fetch_compressed("zstd", |body| zstd::encode_all(body, 3).unwrap())
+            .await;
+    assert_eq!(towns, expected());
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "client")]
+mod common;
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use earthmc::{
+    ClientBuilder,
+    concurrency::{AdaptiveConcurrency, AdaptiveConcurrencyBuilder},
+};
+use parking_lot::Mutex;
+
+use common::RetryImmediately;
+
+#[tokio::test]
+async fn test_concurrency_aimd() {
+    let controller = AdaptiveConcurrencyBuilder::default()
+        .initial_limit(4_usize)
+        .max_limit(6_usize)
+        .build()
+        .unwrap();
+
+    // a full round of successes grows the limit by about one
+    for _ in 0..5 {
+        controller.acquire().await.success();
+    }
+    assert_eq!(controller.limit(), 5);
+
+    // overload halves the limit, but only once for requests that were
+    // already in flight
+    let first = controller.acquire().await;
+    let second = controller.acquire().await;
+    assert_eq!(controller.in_flight(), 2);
+    first.overloaded();
+    second.overloaded();
+    assert_eq!(controller.limit(), 2);
+    assert_eq!(controller.in_flight(), 0);
+
+    // the limit never exceeds the maximum
+    for _ in 0..100 {
+        controller.acquire().await.success();
+    }
+    assert_eq!(controller.limit(), 6);
+
+    assert!(
+        AdaptiveConcurrencyBuilder::default()
+            .initial_limit(0_usize)
+            .build()
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_concurrency_bulk() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let server = common::serve(move |_| {
+        // rate limit the very first request
+        if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+            common::MockResponse::status(429)
+        } else {
+            common::MockResponse::json(include_str!("inputs/town.json"))
+        }
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_url(server.url.clone())
+        .retry_strategy(Arc::new(Mutex::new(RetryImmediately)))
+        .concurrency(Arc::new(AdaptiveConcurrency::default()))
+        .build()
+        .unwrap();
+
+    let names: Vec<String> = (0..250).map(|i| format!("town{i}")).collect();
+    let towns = client.towns_bulk(names).await.unwrap();
+
+    let per_response = serde_json::from_str::<Vec<serde_json::Value>>(
+        include_str!("inputs/town.json"),
+    )
+    .unwrap()
+    .len();
+    // three chunks of at most 100 values, plus one retry
+    assert_eq!(towns.len(), 3 * per_response);
+    assert_eq!(server.requests(), 4);
+    // the 429 halves the limit, and three successes can't grow it back to
+    // where it started, whichever order the responses arrive in
+    let initial = AdaptiveConcurrency::default().limit();
+    assert!(client.concurrency().limit() < initial);
+    assert_eq!(client.concurrency().in_flight(), 0);
+}
+
+#[tokio::test]
+async fn test_concurrency_rising_latency() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    let server = common::serve(move |_| {
+        let response =
+            common::MockResponse::json(include_str!("inputs/town.json"));
+        // slow down after the first request
+        if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+            response
+        } else {
+            response.delayed(Duration::from_millis(300))
+        }
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_url(server.url.clone())
+        .retry_strategy(Arc::new(Mutex::new(RetryImmediately)))
+        .concurrency(Arc::new(AdaptiveConcurrency::default()))
+        .build()
+        .unwrap();
+    let initial = client.concurrency().limit();
+
+    client.towns_bulk(["town0"]).await.unwrap();
+    assert!(client.concurrency().limit() >= initial);
+
+    // the slow response backs off even though it succeeded
+    client.towns_bulk(["town0"]).await.unwrap();
+    assert!(client.concurrency().limit() < initial);
+    assert_eq!(server.requests(), 2);
+}
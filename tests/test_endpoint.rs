@@ -0,0 +1,101 @@
+#![cfg(feature = "client")]
+mod common;
+
+use std::time::Duration;
+
+use common::RetryImmediately;
+use earthmc::ClientBuilder;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_endpoint_failover() {
+    let down = common::unreachable_url().await;
+    let mirror = common::serve(|_| {
+        common::MockResponse::json(include_str!("inputs/server.json"))
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_url(down.clone())
+        .base_url(mirror.url.clone())
+        .retry_strategy(Arc::new(Mutex::new(RetryImmediately)))
+        .build()
+        .unwrap();
+
+    let server = client.server().await.unwrap();
+    assert_eq!(server.version, "1.21.4");
+
+    // the failed endpoint is cooling down, so the mirror is used directly
+    client.server().await.unwrap();
+    assert_eq!(mirror.requests(), 2);
+
+    let health = client.endpoint_health();
+    assert_eq!(health[0].url, down);
+    assert_eq!((health[0].successes, health[0].failures), (0, 1));
+    assert!(!health[0].is_healthy());
+    assert_eq!(health[1].url, mirror.url);
+    assert_eq!((health[1].successes, health[1].failures), (2, 0));
+    assert!(health[1].is_healthy());
+}
+
+#[tokio::test]
+async fn test_client_error_does_not_fail_over() {
+    let primary = common::serve(|_| common::MockResponse::status(404)).await;
+    let mirror = common::serve(|_| {
+        common::MockResponse::json(include_str!("inputs/server.json"))
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_url(primary.url.clone())
+        .base_url(mirror.url.clone())
+        .retry_strategy(Arc::new(Mutex::new(RetryImmediately)))
+        .build()
+        .unwrap();
+
+    // a 404 is retried as the strategy allows, but never on the mirror
+    assert!(client.server().await.is_err());
+    assert_eq!(primary.requests(), 4);
+    assert_eq!(mirror.requests(), 0);
+
+    let health = client.endpoint_health();
+    assert_eq!(health[0].failures, 0);
+    assert!(health[0].is_healthy());
+
+    // the primary is still preferred
+    assert!(client.server().await.is_err());
+    assert_eq!(primary.requests(), 8);
+    assert_eq!(mirror.requests(), 0);
+}
+
+#[tokio::test]
+async fn test_failover_skips_cooling_endpoints() {
+    let first = common::serve(|_| common::MockResponse::status(503)).await;
+    // fails after the first endpoint, so its cool-down ends later
+    let second = common::serve(|_| {
+        common::MockResponse::status(503).delayed(Duration::from_millis(400))
+    })
+    .await;
+    let third = common::serve(|_| {
+        common::MockResponse::json(include_str!("inputs/server.json"))
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_urls([first.url.clone(), second.url.clone(), third.url.clone()])
+        .retry_strategy(Arc::new(Mutex::new(RetryImmediately)))
+        .failover_cooldown(Duration::from_millis(200))
+        .build()
+        .unwrap();
+
+    client.server().await.unwrap();
+    assert_eq!((first.requests(), second.requests()), (1, 1));
+
+    // the first endpoint has recovered but the second is still cooling down,
+    // so the retry skips it
+    client.server().await.unwrap();
+    assert_eq!(first.requests(), 2);
+    assert_eq!(second.requests(), 1);
+    assert_eq!(third.requests(), 2);
+}
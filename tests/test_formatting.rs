@@ -0,0 +1,90 @@
+use earthmc::{
+    colour::Rgb,
+    formatting::{FormattedText, NamedColour, Span, Style, TextColour},
+};
+
+#[test]
+fn test_parse() {
+    let text = FormattedText::parse("§x§A§0§2§0§F§0Doge &l&oBold§r plain");
+    assert_eq!(
+        text.spans,
+        vec![
+            Span {
+                text: "Doge ".to_owned(),
+                style: Style {
+                    colour: Some(TextColour::Hex(Rgb::new(0xA0, 0x20, 0xF0))),
+                    ..Style::default()
+                },
+            },
+            Span {
+                text: "Bold".to_owned(),
+                style: Style {
+                    colour: Some(TextColour::Hex(Rgb::new(0xA0, 0x20, 0xF0))),
+                    bold: true,
+                    italic: true,
+                    ..Style::default()
+                },
+            },
+            Span {
+                text: " plain".to_owned(),
+                style: Style::default(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_colour_resets_formatting() {
+    let text = FormattedText::parse("§lBold§aGreen&#FF0000Red");
+    let styles: Vec<_> = text.spans.iter().map(|span| span.style).collect();
+    assert_eq!(
+        styles,
+        vec![
+            Style {
+                bold: true,
+                ..Style::default()
+            },
+            Style {
+                colour: Some(TextColour::Named(NamedColour::Green)),
+                ..Style::default()
+            },
+            Style {
+                colour: Some(TextColour::Hex(Rgb::new(255, 0, 0))),
+                ..Style::default()
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_literal_prefixes() {
+    let text = FormattedText::parse("Tom & Jerry §z &#12");
+    assert_eq!(text.to_plain(), "Tom & Jerry §z &#12");
+    assert_eq!(text.spans.len(), 1);
+}
+
+#[test]
+fn test_render() {
+    let text = FormattedText::parse("§cRed §l<b>§r and *plain*");
+    assert_eq!(text.to_string(), "Red <b> and *plain*");
+    assert_eq!(
+        text.to_ansi(),
+        "\x1b[38;2;255;85;85mRed \x1b[0m\x1b[38;2;255;85;85m\x1b[1m<b>\x1b[0m \
+         and *plain*"
+    );
+    assert_eq!(
+        text.to_html(),
+        "<span style=\"color: #FF5555\">Red </span><span style=\"color: \
+         #FF5555; font-weight: bold\">&lt;b&gt;</span> and *plain*"
+    );
+    assert_eq!(text.to_discord_markdown(), "Red **<b>** and \\*plain\\*");
+}
+
+#[test]
+fn test_named_colour_codes() {
+    for colour in NamedColour::ALL {
+        assert_eq!(NamedColour::from_code(colour.code()), Some(colour));
+    }
+    assert_eq!(NamedColour::from_code('E'), Some(NamedColour::Yellow));
+    assert_eq!(NamedColour::Gold.rgb(), Rgb::new(0xFF, 0xAA, 0x00));
+}
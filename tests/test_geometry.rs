@@ -0,0 +1,100 @@
+use earthmc::{
+    geometry::{BlockRect, ClaimShape},
+    position::{BlockPosXZ, ChunkPos},
+    town::Town,
+};
+
+fn chunks(coords: &[(i32, i32)]) -> Vec<ChunkPos> {
+    coords.iter().map(|&(x, z)| ChunkPos::new(x, z)).collect()
+}
+
+#[test]
+fn test_ring_with_hole() {
+    // a 3x3 square with the middle chunk left out
+    let ring: Vec<ChunkPos> = (0..3)
+        .flat_map(|x| (0..3).map(move |z| ChunkPos::new(x, z)))
+        .filter(|&chunk| chunk != ChunkPos::new(1, 1))
+        .collect();
+    let shape = ClaimShape::from_chunks(ring);
+
+    assert_eq!(shape.components().len(), 1);
+    let main = shape.main().unwrap();
+    assert_eq!(
+        main.polygon().exterior,
+        [
+            BlockPosXZ::new(0, 0),
+            BlockPosXZ::new(48, 0),
+            BlockPosXZ::new(48, 48),
+            BlockPosXZ::new(0, 48),
+        ]
+    );
+    assert_eq!(main.polygon().holes.len(), 1);
+    assert_eq!(main.polygon().holes[0].len(), 4);
+
+    assert_eq!(shape.area(), 8 * 256);
+    assert_eq!(shape.perimeter(), (12 + 4) * 16);
+    let centroid = shape.centroid().unwrap();
+    assert_eq!((centroid.x, centroid.z), (24.0, 24.0));
+    assert_eq!(
+        shape.bounds(),
+        Some(BlockRect::new(
+            BlockPosXZ::new(0, 0),
+            BlockPosXZ::new(47, 47)
+        ))
+    );
+}
+
+#[test]
+fn test_components() {
+    // chunks touching only at a corner aren't connected
+    let shape = ClaimShape::from_chunks(chunks(&[
+        (0, 0),
+        (1, 1),
+        (2, 1),
+        (-10, -10),
+        (2, 1),
+    ]));
+    assert_eq!(shape.num_chunks(), 4);
+    let sizes: Vec<usize> = shape
+        .components()
+        .iter()
+        .map(|c| c.chunks().len())
+        .collect();
+    assert_eq!(sizes, [2, 1, 1]);
+    assert_eq!(shape.exclaves().len(), 2);
+
+    // holes that meet at a corner stay separate rings
+    let pinched = ClaimShape::from_chunks(chunks(&[
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (0, 1),
+        (2, 1),
+        (0, 2),
+        (1, 2),
+        (1, 3),
+        (2, 3),
+        (3, 3),
+        (3, 2),
+        (3, 1),
+    ]));
+    assert_eq!(pinched.components().len(), 1);
+    let polygon = pinched.main().unwrap().polygon();
+    assert_eq!(polygon.holes.len(), 2);
+    assert_eq!(pinched.perimeter(), (16 + 4 + 4) * 16);
+}
+
+#[test]
+fn test_town_shape() {
+    let towns: Vec<Town> =
+        serde_json::from_str(include_str!("inputs/town.json")).unwrap();
+    let town = &towns[0];
+    let shape = ClaimShape::of_town(town);
+
+    assert_eq!(shape.num_chunks(), town.coordinates.town_blocks.len());
+    assert!(shape.main().unwrap().contains(town.coordinates.home_block));
+    let bounds = shape.bounds().unwrap();
+    for chunk in shape.chunks() {
+        assert!(bounds.contains(chunk.center_block()));
+    }
+}
@@ -0,0 +1,151 @@
+use earthmc::{
+    id::Id,
+    nearby::{DistanceTo, Nearby, NearbyOrigin},
+    position::{BlockPosXZ, ChunkPos},
+    town::Town,
+};
+
+fn id<K>(n: usize) -> Id<K> {
+    format!("00000000-0000-0000-0000-{n:012}").parse().unwrap()
+}
+
+fn town(
+    n: usize,
+    name: &str,
+    home: (i32, i32),
+    nation: Option<(usize, &str)>,
+) -> Town {
+    let home_block = ChunkPos::new(home.0, home.1);
+    let mut town = Town {
+        name: name.to_string(),
+        uuid: id(n),
+        ..Default::default()
+    };
+    town.coordinates.home_block = home_block;
+    town.coordinates.town_blocks =
+        vec![home_block, ChunkPos::new(home.0 + 1, home.1)];
+    town.coordinates.spawn.x = (home.0 * 16 + 8) as f32;
+    town.coordinates.spawn.z = (home.1 * 16 + 8) as f32;
+    if let Some((n, nation)) = nation {
+        town.nation.name = Some(nation.to_string());
+        town.nation.uuid = Some(id(n));
+    }
+    town
+}
+
+fn towns() -> Vec<Town> {
+    let mut capital = town(2, "Capital", (10, 0), Some((1, "Empire")));
+    capital.status.is_capital = true;
+    vec![
+        town(1, "Origin", (0, 0), Some((1, "Empire"))),
+        capital,
+        town(3, "Colony", (0, -20), Some((1, "Empire"))),
+        town(4, "Neighbour", (-5, 0), Some((2, "Kingdom"))),
+        town(5, "Independent", (3, 4), None),
+    ]
+}
+
+#[test]
+fn test_nearby_towns() {
+    let towns = towns();
+    let nearby = Nearby::new(&towns);
+    let origin = NearbyOrigin::Town(nearby.town("origin").unwrap());
+
+    // the origin town is left out
+    let found = nearby.towns(origin, 160.0, DistanceTo::HomeBlock);
+    let names: Vec<&str> = found.iter().map(|t| t.town.name.as_str()).collect();
+    // ties are sorted by name
+    assert_eq!(names, ["Independent", "Neighbour", "Capital"]);
+    assert_eq!(found[0].distance, 80.0);
+    assert_eq!(found[1].distance, 80.0);
+    assert_eq!(found[2].distance, 160.0);
+
+    let capitals = nearby.capitals(origin, 1000.0, DistanceTo::HomeBlock);
+    assert_eq!(capitals.len(), 1);
+    assert_eq!(capitals[0].town.name, "Capital");
+}
+
+#[test]
+fn test_nearby_distances() {
+    let towns = towns();
+    let nearby = Nearby::new(&towns);
+
+    // inside Origin's second chunk
+    let origin = NearbyOrigin::Coordinates(BlockPosXZ::new(20, 8));
+    let nearest = nearby.towns(origin, 0.0, DistanceTo::NearestChunk);
+    assert_eq!(nearest.len(), 1);
+    assert_eq!(nearest[0].town.name, "Origin");
+
+    let spawn = nearby.towns(origin, 15.0, DistanceTo::Spawn);
+    assert_eq!(spawn[0].town.name, "Origin");
+    assert_eq!(spawn[0].distance, 12.0);
+
+    let home = nearby.towns(origin, 5.0, DistanceTo::HomeBlock);
+    assert!(home.is_empty());
+}
+
+#[test]
+fn test_nearby_nations() {
+    let towns = towns();
+    let nearby = Nearby::new(&towns);
+    let origin = NearbyOrigin::Coordinates(BlockPosXZ::new(0, -300));
+
+    let nations = nearby.nations(origin, 1000.0, DistanceTo::HomeBlock);
+    let names: Vec<&str> =
+        nations.iter().map(|n| n.nation.name.as_str()).collect();
+    assert_eq!(names, ["Empire", "Kingdom"]);
+    assert_eq!(nations[0].closest.name, "Colony");
+    assert_eq!(nations[0].distance, 20.0);
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn test_nearby_query_item() {
+    use earthmc::{
+        nearby::NearbySearch,
+        query::{
+            NearbyQueryItem, NearbyQueryItemBuilder, NearbySearchType,
+            NearbyTarget, NearbyTargetType,
+        },
+    };
+
+    let item = |target_type, target, radius| -> NearbyQueryItem {
+        NearbyQueryItemBuilder::default()
+            .target_type(target_type)
+            .target(target)
+            .search_type(NearbySearchType::Town)
+            .radius(radius)
+            .build()
+            .unwrap()
+    };
+    let towns = towns();
+    let nearby = Nearby::new(&towns);
+    let names = |search: NearbySearch| -> Vec<String> {
+        let found = nearby.query(&search).unwrap();
+        found.iter().map(|t| t.town.name.clone()).collect()
+    };
+
+    let by_town = item(
+        NearbyTargetType::Town,
+        NearbyTarget::Town("Origin".into()),
+        160,
+    );
+    assert_eq!(
+        names((&by_town).into()),
+        ["Independent", "Neighbour", "Capital"]
+    );
+
+    let by_coordinate = item(
+        NearbyTargetType::Coordinate,
+        NearbyTarget::Coordinates(BlockPosXZ::new(0, -300)),
+        25,
+    );
+    assert_eq!(names((&by_coordinate).into()), ["Colony"]);
+
+    let unknown = item(
+        NearbyTargetType::Town,
+        NearbyTarget::Town("Atlantis".into()),
+        160,
+    );
+    assert!(nearby.query(&(&unknown).into()).is_none());
+}
@@ -0,0 +1,111 @@
+use earthmc::permission::{
+    ParsePermissionError, PermissionChange, TownyAction, TownyFlag,
+    TownyPermissionFlags, TownyPermissionLevel, TownyPermissionSet,
+    TownyPermissions,
+};
+
+const PERMS: &str = "\
+Build = r---
+Destroy = r---
+Switch = rna-
+ItemUse = rnao
+PvP: OFF  Explosions: OFF  Firespread: ON  Mob Spawns: OFF";
+
+#[test]
+fn test_permission_format() {
+    let perms: TownyPermissions = PERMS.parse().unwrap();
+    assert_eq!(perms.to_string(), PERMS);
+    assert!(perms.switch.ally && !perms.switch.outsider);
+    assert!(perms.flags.fire && !perms.flags.pvp);
+
+    // labels ignore case, lines may come in any order and `-` is optional
+    let loose = "pvp: off explosion: OFF fire: on mobs: off\n\
+        item_use = onar\nswitch = nra\nbuild = r\ndestroy = r";
+    assert_eq!(loose.parse::<TownyPermissions>().unwrap(), perms);
+}
+
+#[test]
+fn test_permission_multi_action_lines() {
+    let perms: TownyPermissions = PERMS.parse().unwrap();
+
+    // the game lists several actions on one line, after a label
+    let status = "\
+Perms: Build = r--- Destroy = r--- Switch = rna- ItemUse = rnao
+PvP: OFF  Explosions: OFF  Firespread: ON  Mob Spawns: OFF";
+    assert_eq!(status.parse::<TownyPermissions>().unwrap(), perms);
+
+    let split = "Build=r--- Destroy=r---\nSwitch=rna- Item Use=rnao\n\
+        PvP: OFF  Explosions: OFF  Firespread: ON  Mob Spawns: OFF";
+    assert_eq!(split.parse::<TownyPermissions>().unwrap(), perms);
+
+    assert_eq!(
+        "Build = r--- Destroy"
+            .parse::<TownyPermissions>()
+            .unwrap_err(),
+        ParsePermissionError::UnexpectedText("Destroy".into())
+    );
+}
+
+#[test]
+fn test_permission_parse_errors() {
+    let err = |s: &str| s.parse::<TownyPermissionSet>().unwrap_err();
+    assert_eq!(err("rx"), ParsePermissionError::InvalidLevel('x'));
+    assert_eq!(err("rr"), ParsePermissionError::DuplicateLevel('r'));
+
+    let err = |s: &str| s.parse::<TownyPermissionFlags>().unwrap_err();
+    assert_eq!(err("PvP: ON"), ParsePermissionError::Missing("Explosions"));
+    assert_eq!(
+        err("PvP: maybe"),
+        ParsePermissionError::InvalidFlagValue("maybe".into())
+    );
+    assert_eq!(
+        err("Keep Inventory: ON"),
+        ParsePermissionError::UnknownFlag("Keep Inventory".into())
+    );
+
+    let missing_build = PERMS.replace("Build = r---\n", "");
+    assert_eq!(
+        missing_build.parse::<TownyPermissions>().unwrap_err(),
+        ParsePermissionError::Missing("Build")
+    );
+}
+
+#[test]
+fn test_permission_diff() {
+    let before: TownyPermissions = PERMS.parse().unwrap();
+    let mut after = before;
+    after.build.nation = true;
+    after.item_use.outsider = false;
+    after.flags.pvp = true;
+
+    let changes = before.diff(&after);
+    assert_eq!(
+        changes,
+        [
+            PermissionChange::Permission {
+                action: TownyAction::Build,
+                level: TownyPermissionLevel::Nation,
+                allowed: true,
+            },
+            PermissionChange::Permission {
+                action: TownyAction::ItemUse,
+                level: TownyPermissionLevel::Outsider,
+                allowed: false,
+            },
+            PermissionChange::Flag {
+                flag: TownyFlag::Pvp,
+                enabled: true,
+            },
+        ]
+    );
+    let lines: Vec<String> = changes.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        [
+            "Build: allowed for nation members",
+            "ItemUse: denied for outsiders",
+            "PvP: ON",
+        ]
+    );
+    assert!(before.diff(&before).is_empty());
+}
@@ -1,4 +1,10 @@
-use earthmc::player_stats::StatMap;
+use std::{collections::BTreeMap, time::Duration};
+
+use earthmc::player_stats::{Stat, StatCategory, StatKind, StatMap, StatUnit};
+
+fn fixture() -> StatMap {
+    serde_json::from_str(include_str!("inputs/player_stats.json")).unwrap()
+}
 
 #[test]
 fn test_player_stats() {
@@ -6,3 +12,104 @@ fn test_player_stats() {
     let parsed: StatMap = serde_json::from_str(raw_json).unwrap();
     insta::assert_debug_snapshot!(parsed);
 }
+
+#[test]
+fn test_lookup() {
+    let stats = fixture();
+    assert_eq!(stats.len(), 76);
+    assert_eq!(stats.get(StatKind::Jump), Some(3546526098));
+    assert_eq!(stats.get_key("jump"), Some(3546526098));
+    assert_eq!(stats.get_key("this_stat_does_not_exist"), Some(6942069));
+    assert_eq!(
+        stats.others().collect::<Vec<_>>(),
+        vec![("this_stat_does_not_exist", 6942069)]
+    );
+    assert_eq!(stats.known().count(), StatKind::COUNT);
+    assert_eq!(
+        stats.iter().last(),
+        Some((Stat::Other("this_stat_does_not_exist".to_owned()), 6942069))
+    );
+
+    let mut stats = StatMap::new();
+    assert_eq!(stats.get(StatKind::Jump), None);
+    assert_eq!(stats.insert(StatKind::Jump, 1), None);
+    assert_eq!(stats.insert(StatKind::Jump, 2), Some(1));
+    assert_eq!(stats.remove(&Stat::Known(StatKind::Jump)), Some(2));
+    assert!(stats.is_empty());
+}
+
+#[test]
+fn test_serialize_round_trip() {
+    let raw: BTreeMap<String, i64> =
+        serde_json::from_str(include_str!("inputs/player_stats.json")).unwrap();
+    let stats = fixture();
+    assert_eq!(
+        serde_json::to_value(&stats).unwrap(),
+        serde_json::to_value(&raw).unwrap()
+    );
+    assert_eq!(BTreeMap::from(stats.clone()), raw);
+    assert_eq!(StatMap::from(raw), stats);
+}
+
+#[test]
+fn test_units() {
+    let stats = fixture();
+    assert_eq!(stats.blocks(StatKind::WalkOneCm), Some(6534815060.95));
+    assert_eq!(stats.kilometres(StatKind::WalkOneCm), Some(6534815.06095));
+    assert_eq!(stats.blocks(StatKind::Jump), None);
+
+    assert_eq!(
+        stats.play_time(),
+        Some(Duration::from_millis(293679404840 * 50))
+    );
+    assert_eq!(stats.sneak_time(), stats.duration(StatKind::SneakTime));
+    assert_eq!(stats.duration(StatKind::Jump), None);
+
+    assert_eq!(stats.hearts(StatKind::DamageDealt), Some(338848188.7));
+    assert_eq!(stats.hearts(StatKind::Jump), None);
+}
+
+#[test]
+fn test_metadata() {
+    let kind = StatKind::InteractWithCartographyTable;
+    assert_eq!(kind.label(), "Interactions with Cartography Table");
+    assert_eq!(kind.category(), StatCategory::Interaction);
+    assert_eq!(kind.unit(), StatUnit::Count);
+
+    let kind = StatKind::WalkUnderWaterOneCm;
+    assert_eq!(kind.label(), "Distance Walked under Water");
+    assert_eq!(kind.category(), StatCategory::Movement);
+    assert_eq!(kind.unit(), StatUnit::Distance);
+
+    assert_eq!(StatKind::PlayTime.unit(), StatUnit::Time);
+    assert_eq!(StatKind::DamageTaken.unit(), StatUnit::Damage);
+    assert_eq!(StatKind::InspectHopper.category(), StatCategory::Redstone);
+
+    let other = Stat::from_key("this_stat_does_not_exist");
+    assert_eq!(other.label(), "This Stat Does Not Exist");
+    assert_eq!(other.category(), StatCategory::Misc);
+    assert_eq!(other.unit(), StatUnit::Count);
+}
+
+#[test]
+fn test_format_value() {
+    assert_eq!(StatKind::Jump.format_value(3546526098), "3,546,526,098");
+    assert_eq!(StatKind::Jump.format_value(-1234), "-1,234");
+    assert_eq!(StatKind::Jump.format_value(12), "12");
+
+    assert_eq!(StatKind::WalkOneCm.format_value(123_400_000), "1,234 km");
+    assert_eq!(StatKind::WalkOneCm.format_value(52_300), "523 blocks");
+    assert_eq!(StatKind::WalkOneCm.format_value(100), "1 block");
+
+    let ticks_per_day = 20 * 60 * 60 * 24;
+    assert_eq!(
+        StatKind::PlayTime.format_value(52 * ticks_per_day),
+        "52 days"
+    );
+    assert_eq!(StatKind::PlayTime.format_value(20 * 60 * 60), "1 hour");
+    assert_eq!(StatKind::PlayTime.format_value(20 * 90), "1 minute");
+    assert_eq!(StatKind::PlayTime.format_value(40), "2 seconds");
+
+    assert_eq!(StatKind::DamageDealt.format_value(340), "17 hearts");
+    assert_eq!(Stat::from_key("unknown_stat").format_value(1000), "1,000");
+}
@@ -0,0 +1,46 @@
+use earthmc::{
+    location::PositionXZ,
+    position::{BlockPos, BlockPosXZ, ChunkPos},
+};
+
+#[test]
+fn test_block_to_chunk() {
+    assert_eq!(BlockPosXZ::new(0, 15).chunk(), ChunkPos::new(0, 0));
+    assert_eq!(BlockPosXZ::new(16, 31).chunk(), ChunkPos::new(1, 1));
+    assert_eq!(BlockPosXZ::new(-1, -16).chunk(), ChunkPos::new(-1, -1));
+    assert_eq!(BlockPosXZ::new(-17, 0).chunk(), ChunkPos::new(-2, 0));
+    assert_eq!(BlockPos::new(-1, 64, 20).chunk(), ChunkPos::new(-1, 1));
+}
+
+#[test]
+fn test_chunk_bounds() {
+    let chunk = ChunkPos::new(-2, 3);
+    assert_eq!(chunk.min_block(), BlockPosXZ::new(-32, 48));
+    assert_eq!(chunk.max_block(), BlockPosXZ::new(-17, 63));
+    assert_eq!(chunk.center_block(), BlockPosXZ::new(-24, 56));
+    assert!(chunk.contains(chunk.min_block()));
+    assert!(chunk.contains(chunk.max_block()));
+    assert!(!chunk.contains(BlockPosXZ::new(-16, 48)));
+}
+
+#[test]
+fn test_from_position() {
+    let pos = PositionXZ { x: -0.5, z: 16.9 };
+    assert_eq!(BlockPosXZ::from(pos), BlockPosXZ::new(-1, 16));
+    assert_eq!(ChunkPos::from(pos), ChunkPos::new(-1, 1));
+}
+
+#[test]
+fn test_wire_format() {
+    let block: BlockPos = serde_json::from_str("[1, -2, 3]").unwrap();
+    assert_eq!(block, BlockPos::new(1, -2, 3));
+    assert_eq!(serde_json::to_string(&block).unwrap(), "[1,-2,3]");
+
+    let column: BlockPosXZ = serde_json::from_str("[4, 5]").unwrap();
+    assert_eq!(column, BlockPosXZ::new(4, 5));
+    assert_eq!(serde_json::to_string(&column).unwrap(), "[4,5]");
+
+    let chunk: ChunkPos = serde_json::from_str("[-6, 7]").unwrap();
+    assert_eq!(chunk, ChunkPos::new(-6, 7));
+    assert_eq!(serde_json::to_string(&chunk).unwrap(), "[-6,7]");
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "client")]
+mod common;
+
+use earthmc::{
+    ClientBuilder,
+    prepared::{HttpMethod, PreparedRequest},
+    query::SimpleQueryBuilder,
+    town::Town,
+    world::World,
+};
+
+#[tokio::test]
+async fn test_prepared() {
+    let server = common::serve(|request| {
+        assert_eq!(request.line, "POST /v3/nostra/towns HTTP/1.1");
+        assert_eq!(request.body, br#"{"query":["London"]}"#);
+        common::MockResponse::json(include_str!("inputs/town.json"))
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_url(server.url.clone())
+        .world(World::Other("nostra".to_string()))
+        .build()
+        .unwrap();
+
+    let query = SimpleQueryBuilder::default()
+        .insert("London")
+        .build()
+        .unwrap();
+    let request = client.prepare_towns(query);
+    assert_eq!(request.method, HttpMethod::Post);
+    assert_eq!(request.url, server.url.join("nostra/towns").unwrap());
+    assert_eq!(
+        request.to_string(),
+        format!(
+            "POST {}nostra/towns\n{{\n  \"query\": [\n    \"London\"\n  ]\n}}",
+            server.url
+        )
+    );
+
+    // replaying a serialized request gives back the raw JSON
+    let serialized = serde_json::to_string(&request).unwrap();
+    let replayed: PreparedRequest = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(replayed, request.clone().with_response());
+
+    let towns = client.execute(&request).await.unwrap();
+    let raw = client.execute(&replayed).await.unwrap();
+    assert_eq!(towns, serde_json::from_value::<Vec<Town>>(raw).unwrap());
+    assert_eq!(server.requests(), 2);
+}
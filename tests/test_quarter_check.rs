@@ -0,0 +1,93 @@
+use earthmc::{
+    id::Id,
+    position::{BlockPos, ChunkPos},
+    quarter::{Quarter, QuarterCuboid},
+    quarter_check::{QuarterIssue, check_quarters},
+    town::Town,
+};
+
+fn cuboid(a: [i32; 3], b: [i32; 3]) -> QuarterCuboid {
+    QuarterCuboid::new(a.into(), b.into())
+}
+
+fn quarter(name: &str, cuboids: Vec<QuarterCuboid>) -> Quarter {
+    let mut quarter = Quarter {
+        name: name.to_string(),
+        cuboids,
+        ..Default::default()
+    };
+    quarter.stats.volume = quarter.volume() as i32;
+    quarter
+}
+
+#[test]
+fn test_cuboid() {
+    let a = cuboid([3, 70, -2], [0, 64, 5]);
+    assert_eq!(a.min(), BlockPos::new(0, 64, -2));
+    assert_eq!(a.max(), BlockPos::new(3, 70, 5));
+    assert_eq!(a.normalized(), cuboid([0, 64, -2], [3, 70, 5]));
+    assert_eq!(a.volume(), 4 * 7 * 8);
+    assert!(a.contains(BlockPos::new(3, 64, -2)));
+    assert!(!a.contains(BlockPos::new(4, 64, -2)));
+
+    let b = cuboid([2, 60, 5], [10, 65, 10]);
+    assert_eq!(a.intersection(&b), Some(cuboid([2, 64, 5], [3, 65, 5])));
+    assert!(!a.intersects(&cuboid([4, 64, 0], [5, 64, 0])));
+
+    let chunks: Vec<ChunkPos> = a.chunks().collect();
+    assert_eq!(chunks, [ChunkPos::new(0, -1), ChunkPos::new(0, 0)]);
+}
+
+#[test]
+fn test_quarter_fixture_volume() {
+    let quarters: Vec<Quarter> =
+        serde_json::from_str(include_str!("inputs/quarter.json")).unwrap();
+    for quarter in quarters {
+        assert_eq!(quarter.volume(), quarter.stats.volume as i64);
+    }
+}
+
+#[test]
+fn test_check_quarters() {
+    let mut town = Town::default();
+    town.coordinates.town_blocks = vec![ChunkPos::new(0, 0)];
+
+    let flat = quarter("Flat", vec![cuboid([0, 64, 0], [7, 70, 7])]);
+    let shop = quarter("Shop", vec![cuboid([6, 64, 6], [12, 64, 12])]);
+    let mut annex = quarter("Annex", vec![cuboid([14, 64, 0], [17, 66, 2])]);
+    annex.stats.volume = 1;
+    let mut elsewhere =
+        quarter("Elsewhere", vec![cuboid([0, 0, 0], [1, 1, 1])]);
+    elsewhere.town.uuid = "00000000-0000-0000-0000-000000000001"
+        .parse::<Id<Town>>()
+        .unwrap();
+
+    let issues = check_quarters(&town, [&flat, &shop, &annex, &elsewhere]);
+    let summary: Vec<String> = issues
+        .iter()
+        .map(|issue| match issue {
+            QuarterIssue::Overlap { quarters, overlaps } => format!(
+                "{} overlaps {} by {}",
+                quarters[0].name,
+                quarters[1].name,
+                overlaps.iter().map(QuarterCuboid::volume).sum::<i64>()
+            ),
+            QuarterIssue::OutsideTown { quarter, chunks } => {
+                format!("{} outside {chunks:?}", quarter.name)
+            }
+            QuarterIssue::VolumeMismatch {
+                quarter,
+                reported,
+                computed,
+            } => format!("{} is {computed}, not {reported}", quarter.name),
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            "Flat overlaps Shop by 4",
+            "Annex outside [ChunkPos { x: 1, z: 0 }]",
+            "Annex is 36, not 1",
+        ]
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "client")]
+mod common;
+
+use std::str::FromStr;
+
+use earthmc::{
+    ClientBuilder,
+    errors::Error,
+    id::{PlayerId, TownId},
+    named_id::{NamedId, NamedIdOpt},
+    player::Player,
+    town::Town,
+};
+
+#[tokio::test]
+async fn test_resolve() {
+    let server = common::serve(|request| {
+        let fixture = if request.line.contains("/aurora/players ") {
+            include_str!("inputs/player.json")
+        } else if request.line.contains("/aurora/towns ") {
+            include_str!("inputs/town.json")
+        } else {
+            return common::MockResponse::status(404);
+        };
+        common::MockResponse::json(fixture)
+    })
+    .await;
+    let client = ClientBuilder::default()
+        .base_url(server.url.clone())
+        .build()
+        .unwrap();
+
+    let fix: NamedId<Player> = NamedId {
+        name: "Fix".to_string(),
+        uuid: PlayerId::from_str("7395d056-536a-4cf3-9c96-6c7a7df6897a")
+            .unwrap(),
+    };
+    let greed: NamedId<Player> = NamedId {
+        name: "CorruptedGreed".to_string(),
+        uuid: PlayerId::from_str("f17d77ab-aed4-44e7-96ef-ec9cd473eda3")
+            .unwrap(),
+    };
+    let berlin: NamedId<Town> = NamedId {
+        name: "Berlin".to_string(),
+        uuid: TownId::from_str("82a1ecc6-d40c-4ee4-9bff-5b4acecb4e63").unwrap(),
+    };
+    let no_town: NamedIdOpt<Town> = NamedIdOpt {
+        name: None,
+        uuid: None,
+    };
+
+    let player = client.resolve(&fix).await.unwrap();
+    assert_eq!(player.name, "Fix");
+
+    // empty optional references resolve without a request
+    assert!(client.resolve(&no_town).await.unwrap().is_none());
+    assert_eq!(server.requests(), 1);
+
+    // references to entities that are gone
+    let gone =
+        TownId::from_str("00000000-0000-0000-0000-000000000000").unwrap();
+    assert!(matches!(
+        client.resolve(&gone).await,
+        Err(Error::NotFound(uuid)) if uuid == gone.uuid()
+    ));
+
+    // duplicates are fetched once and returned once, in order
+    let players = client.resolve_all([&greed, &fix, &greed]).await.unwrap();
+    let names: Vec<_> = players.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["CorruptedGreed", "Fix"]);
+
+    // one request per kind for the whole batch
+    let before = server.requests();
+    let resolved = client
+        .resolver()
+        .insert(&berlin)
+        .insert_all([&fix, &greed, &fix])
+        .insert(&no_town)
+        .resolve()
+        .await
+        .unwrap();
+    assert_eq!(server.requests() - before, 2);
+    assert_eq!(resolved.get(&berlin).unwrap().name, "Berlin");
+    assert_eq!(resolved.get_all([&fix, &greed]).len(), 2);
+    assert!(resolved.get(&no_town).is_none());
+    assert_eq!(resolved.players().len(), 2);
+    assert!(resolved.nations().is_empty());
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "schemars")]
+use earthmc::{
+    discord_link::DiscordLink, location::LocationInfo,
+    mystery_master::MysteryMaster, named_id::NamedId, nation::Nation,
+    player::Player, player_stats::StatMap, quarter::Quarter, server::Server,
+    town::Town,
+};
+use schemars::{JsonSchema, schema_for};
+
+fn assert_valid<T: JsonSchema>(raw_json: &str) {
+    let schema = serde_json::to_value(schema_for!(T)).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    let instance: serde_json::Value = serde_json::from_str(raw_json).unwrap();
+    let errors: Vec<_> = validator
+        .iter_errors(&instance)
+        .map(|error| format!("{} at {}", error, error.instance_path))
+        .collect();
+    assert!(
+        errors.is_empty(),
+        "{} doesn't match its schema: {errors:#?}",
+        T::schema_name()
+    );
+}
+
+#[test]
+fn test_fixtures_match_schemas() {
+    assert_valid::<Vec<DiscordLink>>(include_str!("inputs/discord.json"));
+    assert_valid::<Vec<LocationInfo>>(include_str!("inputs/location.json"));
+    assert_valid::<Vec<MysteryMaster>>(include_str!(
+        "inputs/mystery_master.json"
+    ));
+    assert_valid::<NamedId<Player>>(include_str!("inputs/named_id.json"));
+    assert_valid::<Vec<Nation>>(include_str!("inputs/nation.json"));
+    assert_valid::<Vec<Player>>(include_str!("inputs/player.json"));
+    assert_valid::<StatMap>(include_str!("inputs/player_stats.json"));
+    assert_valid::<Vec<Quarter>>(include_str!("inputs/quarter.json"));
+    assert_valid::<Server>(include_str!("inputs/server.json"));
+    assert_valid::<Vec<Town>>(include_str!("inputs/town.json"));
+}
+
+#[test]
+fn test_custom_formats() {
+    let schema = serde_json::to_value(schema_for!(Town)).unwrap();
+    let validator = jsonschema::validator_for(&schema).unwrap();
+    let mut town: serde_json::Value =
+        serde_json::from_str(include_str!("inputs/town.json")).unwrap();
+    let town = &mut town[0];
+    assert!(validator.is_valid(town));
+
+    town["perms"]["build"] = serde_json::json!([true, false, false]);
+    assert!(!validator.is_valid(town));
+    town["perms"]["build"] =
+        serde_json::json!({ "resident": true, "nation": false });
+    assert!(!validator.is_valid(town));
+}
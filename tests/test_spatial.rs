@@ -0,0 +1,82 @@
+use earthmc::{
+    geometry::BlockRect,
+    location::{LocationInfo, PositionXZ},
+    position::{BlockPosXZ, ChunkPos},
+    spatial::TownIndex,
+    town::Town,
+};
+
+fn towns() -> Vec<Town> {
+    serde_json::from_str(include_str!("inputs/town.json")).unwrap()
+}
+
+#[test]
+fn test_location_info() {
+    let towns = towns();
+    let index: TownIndex = towns.iter().collect();
+    let london = &towns[0];
+    let claims: usize =
+        towns.iter().map(|t| t.coordinates.town_blocks.len()).sum();
+    assert_eq!(index.num_chunks(), claims);
+
+    let home = london.coordinates.home_block.min_block();
+    let location = PositionXZ {
+        x: home.x as f32 + 0.5,
+        z: home.z as f32 + 15.9,
+    };
+    let info = index.location_info(location);
+    assert!(!info.is_wilderness);
+    assert_eq!(info.town.name.as_deref(), Some("London"));
+    assert_eq!(info.town.uuid, Some(london.uuid));
+    assert_eq!(info.nation, london.nation);
+
+    // the API reports wilderness with no town or nation
+    let far_away = PositionXZ {
+        x: 1_000_000.0,
+        z: -1_000_000.0,
+    };
+    assert_eq!(
+        index.location_info(far_away),
+        LocationInfo {
+            location: far_away,
+            is_wilderness: true,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_towns_in() {
+    let mut towns = towns();
+    let mut other = towns[0].clone();
+    other.name = "Elsewhere".to_string();
+    other.coordinates.town_blocks = vec![ChunkPos::new(-500, -500)];
+    towns.push(other);
+    let index = TownIndex::new(&towns);
+
+    let home = towns[0].coordinates.home_block;
+    let near_home = BlockRect::of_chunk(home);
+    let names = |rect| -> Vec<String> {
+        index
+            .towns_in(rect)
+            .into_iter()
+            .map(|t| t.town.name.clone())
+            .collect()
+    };
+    assert_eq!(names(near_home), ["London"]);
+    assert_eq!(
+        names(BlockRect::new(
+            BlockPosXZ::new(-8000, -8000),
+            BlockPosXZ::new(-7990, -7990)
+        )),
+        ["Elsewhere"]
+    );
+
+    // big enough to scan the claims rather than the rectangle
+    let everything = BlockRect::new(
+        BlockPosXZ::new(-100_000, -100_000),
+        BlockPosXZ::new(100_000, 100_000),
+    );
+    assert_eq!(names(everything), ["London", "Berlin", "Elsewhere"]);
+    assert!(names(BlockRect::of_chunk(ChunkPos::new(10_000, 0))).is_empty());
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use earthmc::{
+    player_stats::{Stat, StatKind, StatMap},
+    stat_history::{StatDiff, StatHistory, StatSample},
+    timestamp::Timestamp,
+};
+
+const HOUR: i64 = 60 * 60 * 1000;
+
+fn sample(hours: i64, walked: i64, jumps: i64) -> StatSample {
+    let stats = StatMap::from_iter([
+        (Stat::Known(StatKind::WalkOneCm), walked),
+        (Stat::Known(StatKind::Jump), jumps),
+    ]);
+    StatSample::new(Timestamp::from_millis(hours * HOUR), stats)
+}
+
+#[test]
+fn test_stat_diff() {
+    let diff =
+        StatDiff::between(&sample(0, 1_000, 100), &sample(2, 1_500, 300));
+
+    assert_eq!(diff.elapsed(), Duration::from_secs(2 * 60 * 60));
+    assert_eq!(diff.delta(StatKind::WalkOneCm), Some(500));
+    assert_eq!(diff.rate_per_hour(StatKind::WalkOneCm), Some(250.0));
+    assert_eq!(diff.delta(StatKind::Deaths), None);
+    assert_eq!(diff.deltas().get(StatKind::Jump), Some(200));
+
+    // jumps tripled while distance walked only grew by half
+    let movers = diff.top_movers(1);
+    assert_eq!(movers.len(), 1);
+    assert_eq!(movers[0].stat, Stat::Known(StatKind::Jump));
+    assert_eq!(movers[0].relative(), Some(2.0));
+}
+
+#[test]
+fn test_stat_history() {
+    let mut history = StatHistory::new(3);
+    assert!(history.diff().is_none());
+
+    for hours in 0..5 {
+        history.push(sample(hours, hours * 100, 0));
+    }
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.oldest(), Some(&sample(2, 200, 0)));
+
+    let diff = history.diff().unwrap();
+    assert_eq!(diff.delta(StatKind::WalkOneCm), Some(200));
+
+    let diff = history.diff_over(Duration::from_secs(60 * 60)).unwrap();
+    assert_eq!(diff.from(), Timestamp::from_millis(3 * HOUR));
+    assert_eq!(diff.delta(StatKind::WalkOneCm), Some(100));
+
+    assert!(history.diff_over(Duration::ZERO).is_none());
+}
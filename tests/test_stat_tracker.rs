@@ -0,0 +1,52 @@
+#![cfg(feature = "client")]
+mod common;
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use earthmc::{
+    ClientBuilder, player_stats::StatKind, stat_tracker::StatTracker,
+};
+
+#[tokio::test]
+async fn test_stat_tracker() {
+    let readings = Arc::new(AtomicUsize::new(0));
+    let counter = readings.clone();
+    let server = common::serve(move |_| {
+        let walked = 1_000 + 500 * counter.fetch_add(1, Ordering::SeqCst);
+        common::MockResponse::json(&format!(r#"{{"walk_one_cm":{walked}}}"#))
+    })
+    .await;
+
+    let client = ClientBuilder::default()
+        .base_url(server.url.clone())
+        .build()
+        .unwrap();
+    let tracker = StatTracker::new(client, Duration::from_millis(10), 2);
+
+    let first = tracker.sample().await.unwrap();
+    assert_eq!(first.stats.get(StatKind::WalkOneCm), Some(1_000));
+    assert!(tracker.diff_over(Duration::from_secs(60)).is_none());
+
+    tracker.sample().await.unwrap();
+    tracker.sample().await.unwrap();
+    let history = tracker.history();
+    assert_eq!(history.len(), 2);
+
+    let diff = history.diff().unwrap();
+    assert_eq!(diff.delta(StatKind::WalkOneCm), Some(500));
+    assert_eq!(
+        tracker.latest().unwrap().stats.get_key("walk_one_cm"),
+        Some(2_000)
+    );
+
+    let task = tracker.spawn();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    task.abort();
+    assert!(server.requests() > 3);
+}
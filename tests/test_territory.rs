@@ -0,0 +1,94 @@
+use earthmc::{
+    id::Id,
+    named_id::NamedId,
+    nation::Nation,
+    position::ChunkPos,
+    territory::{Diplomacy, TerritoryMap},
+    town::Town,
+};
+
+fn id<K>(n: usize) -> Id<K> {
+    format!("00000000-0000-0000-0000-{n:012}").parse().unwrap()
+}
+
+fn town(n: usize, nation: Option<usize>, chunks: &[(i32, i32)]) -> Town {
+    let mut town = Town {
+        name: format!("Town {n}"),
+        uuid: id(n),
+        ..Default::default()
+    };
+    if let Some(nation) = nation {
+        town.nation.name = Some(format!("Nation {nation}"));
+        town.nation.uuid = Some(id(nation));
+    }
+    town.coordinates.town_blocks =
+        chunks.iter().map(|&(x, z)| ChunkPos::new(x, z)).collect();
+    town
+}
+
+/// Nation 1 has two towns, one of which borders nation 2 along three chunk
+/// edges. Nation 3 is far away, and town 5 has no nation.
+fn towns() -> Vec<Town> {
+    vec![
+        town(1, Some(1), &[(0, 0), (0, 1), (0, 2), (-1, 0)]),
+        town(2, Some(1), &[(-2, 0), (-3, 0)]),
+        town(3, Some(2), &[(1, 0), (1, 1), (1, 2), (2, 2)]),
+        town(4, Some(3), &[(100, 100)]),
+        town(5, None, &[(-4, 0)]),
+    ]
+}
+
+#[test]
+fn test_nation_territory() {
+    let towns = towns();
+    let map = TerritoryMap::new(&towns);
+
+    let sizes: Vec<(String, usize)> = map
+        .nations()
+        .iter()
+        .map(|t| (t.nation.name.clone(), t.num_chunks()))
+        .collect();
+    assert_eq!(
+        sizes,
+        [
+            ("Nation 1".to_string(), 6),
+            ("Nation 2".to_string(), 4),
+            ("Nation 3".to_string(), 1),
+        ]
+    );
+    let nation_1 = map.nation(id(1)).unwrap();
+    assert_eq!(nation_1.towns.len(), 2);
+    assert_eq!(nation_1.area(), 6 * 256);
+    assert_eq!(nation_1.shape.components().len(), 1);
+}
+
+#[test]
+fn test_border_graph() {
+    let towns = towns();
+    let map = TerritoryMap::new(&towns);
+
+    assert_eq!(map.borders().len(), 1);
+    let border = map.border_between(id(2), id(1)).unwrap();
+    assert_eq!(border.edges, 3);
+    assert_eq!(border.length(), 48);
+    let neighbours: Vec<&str> =
+        map.neighbours(id(1)).map(|n| n.name.as_str()).collect();
+    assert_eq!(neighbours, ["Nation 2"]);
+    assert!(map.border_between(id(1), id(3)).is_none());
+
+    // the nationless town next to town 2 doesn't make a frontier
+    let frontier: Vec<&str> =
+        map.frontier_towns().map(|f| f.town.name.as_str()).collect();
+    assert_eq!(frontier, ["Town 1", "Town 3"]);
+    assert!(!map.is_frontier(id(2)));
+
+    let nation = Nation {
+        uuid: id(1),
+        allies: vec![NamedId {
+            name: "Nation 2".to_string(),
+            uuid: id(2),
+        }],
+        ..Default::default()
+    };
+    assert_eq!(border.diplomacy(&nation), Some(Diplomacy::Allied));
+}
@@ -0,0 +1,57 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use earthmc::{player::Player, timestamp::Timestamp, town::Town};
+
+#[test]
+fn test_timestamp() {
+    let players: Vec<Player> =
+        serde_json::from_str(include_str!("inputs/player.json")).unwrap();
+    let fix = &players[0];
+
+    let last_online = fix.timestamps.last_online.unwrap();
+    assert_eq!(last_online.as_millis(), 1748118485229);
+    assert_eq!(
+        last_online.to_system_time(),
+        UNIX_EPOCH + Duration::from_millis(1748118485229)
+    );
+    assert_eq!(Timestamp::from(last_online.to_system_time()), last_online);
+    assert_eq!(
+        last_online.duration_since(fix.timestamps.registered),
+        Some(Duration::from_millis(1748118485229 - 1641749298700))
+    );
+    assert_eq!(fix.timestamps.registered.duration_since(last_online), None);
+
+    // serialized back in the API's format
+    assert_eq!(
+        serde_json::to_string(&last_online).unwrap(),
+        "1748118485229"
+    );
+
+    let offline_for = fix.offline_for().unwrap();
+    let expected = SystemTime::now()
+        .duration_since(last_online.to_system_time())
+        .unwrap();
+    assert!(expected - offline_for < Duration::from_secs(1));
+
+    let towns: Vec<Town> =
+        serde_json::from_str(include_str!("inputs/town.json")).unwrap();
+    assert!(towns[0].age() > Duration::from_secs(60 * 60 * 24 * 365));
+
+    #[cfg(feature = "chrono")]
+    {
+        let date_time = last_online.to_chrono();
+        assert_eq!(
+            date_time,
+            chrono::DateTime::from_timestamp(1748118485, 229_000_000).unwrap()
+        );
+        assert_eq!(Timestamp::from(date_time), last_online);
+    }
+
+    #[cfg(feature = "time")]
+    {
+        let date_time = last_online.to_offset_date_time();
+        assert_eq!(date_time.unix_timestamp(), 1748118485);
+        assert_eq!(date_time.millisecond(), 229);
+        assert_eq!(Timestamp::from(date_time), last_online);
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "client")]
+use std::{fmt::Debug, hash::Hash};
+
+use earthmc::{
+    discord_link::DiscordLink,
+    id::{NationId, PlayerId, QuarterId, TownId},
+    location::{LocationInfo, PositionXZ},
+    mystery_master::{MysteryMaster, MysteryMasterChangeKind},
+    named_id::{NamedId, NamedIdOpt},
+    nation::{
+        Nation, NationCoordinates, NationRankKind, NationStats, NationStatus,
+        NationTimestamps,
+    },
+    permission::{TownyPermissionFlags, TownyPermissionSet, TownyPermissions},
+    player::{
+        Player, PlayerRanks, PlayerStats, PlayerStatus, PlayerTimestamps,
+    },
+    player_stats::{Stat, StatMap},
+    quarter::{
+        Quarter, QuarterCuboid, QuarterKind, QuarterStats, QuarterStatus,
+        QuarterTimestamps,
+    },
+    query::{
+        DiscordQuery, DiscordQueryItem, LocationQuery, NationQuery,
+        NearbyQuery, NearbyQueryItem, NearbySearchType, NearbyTarget,
+        NearbyTargetType, PlayerQuery, StrOrId, TownQuery, UuidQuery,
+    },
+    server::{
+        MoonPhase, Server, ServerStats, ServerStatus, ServerTimestamps,
+        VoteParty,
+    },
+    town::{
+        Town, TownCoordinates, TownRankKind, TownStats, TownStatus,
+        TownTimestamps,
+    },
+    world::World,
+    world_location::WorldLocation,
+};
+
+/// Types that can be cloned, compared and printed.
+fn model<T: Clone + PartialEq + Debug>() {}
+
+/// Models without floating point fields can also be hashed.
+fn hashable<T: Clone + Eq + Hash + Debug>() {}
+
+fn default<T: Default>() {}
+
+#[test]
+fn test_traits() {
+    model::<Town>();
+    model::<TownStats>();
+    model::<TownCoordinates>();
+    model::<Nation>();
+    model::<NationStats>();
+    model::<NationCoordinates>();
+    model::<Player>();
+    model::<PlayerStats>();
+    model::<Quarter>();
+    model::<QuarterStats>();
+    model::<LocationInfo>();
+    model::<PositionXZ>();
+    model::<WorldLocation>();
+
+    hashable::<TownTimestamps>();
+    hashable::<TownStatus>();
+    hashable::<TownRankKind>();
+    hashable::<NationTimestamps>();
+    hashable::<NationStatus>();
+    hashable::<NationRankKind>();
+    hashable::<PlayerTimestamps>();
+    hashable::<PlayerStatus>();
+    hashable::<PlayerRanks>();
+    hashable::<QuarterKind>();
+    hashable::<QuarterTimestamps>();
+    hashable::<QuarterStatus>();
+    hashable::<QuarterCuboid>();
+    hashable::<Server>();
+    hashable::<ServerTimestamps>();
+    hashable::<ServerStatus>();
+    hashable::<ServerStats>();
+    hashable::<VoteParty>();
+    hashable::<MoonPhase>();
+    hashable::<DiscordLink>();
+    hashable::<MysteryMaster>();
+    hashable::<MysteryMasterChangeKind>();
+    hashable::<TownyPermissionSet>();
+    hashable::<TownyPermissions>();
+    hashable::<TownyPermissionFlags>();
+    hashable::<Stat>();
+    hashable::<StatMap>();
+    hashable::<World>();
+    hashable::<TownId>();
+    hashable::<NationId>();
+    hashable::<PlayerId>();
+    hashable::<QuarterId>();
+    hashable::<NamedId<Town>>();
+    hashable::<NamedIdOpt<Nation>>();
+
+    hashable::<TownQuery>();
+    hashable::<NationQuery>();
+    hashable::<PlayerQuery>();
+    hashable::<StrOrId<Town>>();
+    hashable::<NearbyQuery>();
+    hashable::<NearbyQueryItem>();
+    hashable::<NearbyTargetType>();
+    hashable::<NearbySearchType>();
+    hashable::<NearbyTarget>();
+    hashable::<UuidQuery>();
+    hashable::<DiscordQuery>();
+    hashable::<DiscordQueryItem>();
+    hashable::<LocationQuery>();
+
+    default::<Town>();
+    default::<Nation>();
+    default::<Player>();
+    default::<Quarter>();
+    default::<ServerStats>();
+    default::<NamedId<Player>>();
+    default::<NamedIdOpt<Town>>();
+    default::<DiscordLink>();
+    default::<MysteryMaster>();
+    default::<TownyPermissions>();
+    default::<StatMap>();
+    default::<LocationInfo>();
+    default::<World>();
+    default::<TownQuery>();
+    default::<NearbyQuery>();
+    default::<UuidQuery>();
+    default::<DiscordQuery>();
+    default::<LocationQuery>();
+}
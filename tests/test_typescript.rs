@@ -0,0 +1,109 @@
+#![cfg(feature = "ts-rs")]
+use earthmc::{
+    colour::{Rgb, Rgba},
+    discord_link::DiscordLink,
+    id::TownId,
+    location::{LocationInfo, PositionXZ},
+    mystery_master::{MysteryMaster, MysteryMasterChangeKind},
+    named_id::NamedIdGeneric,
+    nation::{
+        Nation, NationCoordinates, NationRankKind, NationStats, NationStatus,
+        NationTimestamps,
+    },
+    permission::{TownyPermissionFlags, TownyPermissionSet, TownyPermissions},
+    player::{
+        Player, PlayerRanks, PlayerStats, PlayerStatus, PlayerTimestamps,
+    },
+    player_stats::StatMap,
+    position::{BlockPos, BlockPosXZ, ChunkPos},
+    quarter::{
+        Quarter, QuarterCuboid, QuarterKind, QuarterStats, QuarterStatus,
+        QuarterTimestamps,
+    },
+    server::{
+        MoonPhase, Server, ServerStats, ServerStatus, ServerTimestamps,
+        VoteParty,
+    },
+    timestamp::Timestamp,
+    town::{
+        Town, TownCoordinates, TownRankKind, TownStats, TownStatus,
+        TownTimestamps,
+    },
+    world_location::WorldLocation,
+};
+use ts_rs::TS;
+
+macro_rules! declarations {
+    ($($ty:ty),* $(,)?) => {
+        [$(<$ty as TS>::decl()),*].join("\n")
+    };
+}
+
+#[test]
+fn test_typescript_declarations() {
+    let declarations = declarations![
+        Rgb,
+        Rgba,
+        DiscordLink,
+        LocationInfo,
+        PositionXZ,
+        MysteryMaster,
+        MysteryMasterChangeKind,
+        NamedIdGeneric<String, String>,
+        Nation,
+        NationTimestamps,
+        NationStatus,
+        NationStats,
+        NationCoordinates,
+        NationRankKind,
+        TownyPermissionSet,
+        TownyPermissions,
+        TownyPermissionFlags,
+        Player,
+        PlayerTimestamps,
+        PlayerStatus,
+        PlayerStats,
+        PlayerRanks,
+        StatMap,
+        BlockPos,
+        BlockPosXZ,
+        ChunkPos,
+        Quarter,
+        QuarterKind,
+        QuarterTimestamps,
+        QuarterStatus,
+        QuarterStats,
+        QuarterCuboid,
+        Server,
+        ServerTimestamps,
+        ServerStatus,
+        ServerStats,
+        VoteParty,
+        MoonPhase,
+        Timestamp,
+        Town,
+        TownTimestamps,
+        TownStatus,
+        TownStats,
+        TownCoordinates,
+        TownRankKind,
+        WorldLocation,
+    ];
+    insta::assert_snapshot!(declarations);
+}
+
+#[test]
+fn test_serde_renames() {
+    assert!(Nation::decl().contains("king: NamedIdGeneric<string, string>"));
+    assert!(Quarter::decl().contains("type: QuarterKind"));
+    assert!(PlayerStatus::decl().contains("isNPC: boolean"));
+    assert!(TownCoordinates::decl().contains("homeBlock: ChunkPos"));
+}
+
+#[test]
+fn test_id_declarations() {
+    assert_eq!(TownId::inline(), "string");
+    assert_eq!(TownId::inline_flattened(), "string");
+    assert_eq!(TownId::decl(), "type Id = string;");
+    assert_eq!(TownId::decl_concrete(), "type Id = string;");
+}